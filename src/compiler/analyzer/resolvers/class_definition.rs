@@ -15,6 +15,7 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<ClassDefinitionNode, ()> {
     // no `cl` keyword
     // example:
     // { prop $_, method=(self $_){do something...} }
+    // Parent { prop $_, ... }
 
     if tokens.len() == 0 {
         return Err(syntax_error("missing class body")?);
@@ -23,7 +24,17 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<ClassDefinitionNode, ()> {
     let mut properties = Vec::<Property>::new();
     let mut method_nodes = Vec::<Rc<FunctionDefinitionNode>>::new();
 
-    let first_token = tokens.pop_front().unwrap();
+    let mut parent = None;
+    if let Some(Token::Identi(_)) = tokens.front() {
+        let Some(Token::Identi(parent_name)) = tokens.pop_front() else {
+            unreachable!()
+        };
+        parent = Some(parent_name);
+    }
+
+    let Some(first_token) = tokens.pop_front() else {
+        return Err(syntax_error("missing class body")?);
+    };
 
     if first_token == Token::Paren(Paren::LeftBrace) {
         loop {
@@ -49,6 +60,8 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<ClassDefinitionNode, ()> {
                             UserDefinedFnParam {
                                 type__: ValueType::Object,
                                 identi: String::from("self"),
+                                default: None,
+                                is_rest: false,
                             },
                         );
                         method_node.name = Some(identi);
@@ -72,6 +85,7 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<ClassDefinitionNode, ()> {
         return Err(syntax_error("expected class-definition body")?);
     }
     Ok(ClassDefinitionNode {
+        parent,
         properties,
         method_nodes,
     })
@@ -1,6 +1,6 @@
 use crate::compiler::tokenizer::token::{Token, TokenVec};
 use crate::public::compile_time::ast::ast_enum::ASTNode;
-use crate::public::compile_time::ast::types::ExpressionNode;
+use crate::public::compile_time::ast::types::{ExpressionNode, VariableNode};
 use crate::public::compile_time::parens::Paren;
 use crate::public::error::syntax_error;
 use crate::public::value::symbols::Symbols;
@@ -25,7 +25,19 @@ pub fn resolve(var_node: ASTNode, tokens: &mut TokenVec) -> Result<ASTNode, ()>
 
                 // if object-method, insert object variable as `self` param
                 if let ASTNode::ObjectReading(node) = var_node {
-                    let obj_clone = node.obj_node.clone();
+                    let obj_clone = match &node.obj_node {
+                        // the parent method invoked by `super.method()`
+                        // receives the current `self`
+                        ASTNode::Variable(sub_node) if sub_node.name == "super" => {
+                            ASTNode::Variable(
+                                VariableNode {
+                                    name: String::from("self"),
+                                }
+                                .into(),
+                            )
+                        }
+                        _ => node.obj_node.clone(),
+                    };
                     let inserted_expression = ExpressionNode {
                         elements: vec![obj_clone],
                     };
@@ -62,6 +74,12 @@ pub fn resolve(var_node: ASTNode, tokens: &mut TokenVec) -> Result<ASTNode, ()>
                     return Ok(var_node);
                 }
             }
+            Token::Question | Token::Colon => {
+                // examples:
+                // ... a ? b : c
+                tokens.push_front(next_token);
+                return Ok(var_node);
+            }
             _ => {
                 let msg = format!("unexpected token `{}`", next_token);
                 return Err(syntax_error(&msg)?);
@@ -5,6 +5,10 @@ use crate::public::compile_time::ast::types::InvocationNode;
 use crate::public::compile_time::parens::Paren;
 
 pub fn resolve(caller: ASTNode, tokens: &mut TokenVec) -> Result<InvocationNode, ()> {
-    let params = list::resolve(tokens, Paren::RightParen)?;
-    Ok(InvocationNode { caller, params })
+    let (params, named_params) = list::arguments_resolve(tokens, Paren::RightParen)?;
+    Ok(InvocationNode {
+        caller,
+        params,
+        named_params,
+    })
 }
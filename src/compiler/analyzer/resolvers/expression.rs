@@ -3,7 +3,7 @@ use crate::compiler::analyzer::resolvers::{class_definition, function_definition
 use crate::compiler::tokenizer::token::{Token, TokenVec};
 use crate::public::compile_time::ast::ast_enum::{ASTNode, ASTVec};
 use crate::public::compile_time::ast::types::{
-    ExpressionNode, ImportNode, ModuleType, VariableNode,
+    ExpressionNode, IfStatement, ImportNode, ModuleType, VariableNode,
 };
 use crate::public::compile_time::keywords::Keyword;
 use crate::public::compile_time::parens::Paren;
@@ -13,7 +13,7 @@ use crate::public::error::{
 use crate::public::value::symbols::Symbols;
 
 use super::symbol_priority::compare;
-use super::{array, lazy_expression};
+use super::{array, lazy_expression, statement};
 
 pub fn resolve(tokens: &mut TokenVec) -> Result<ExpressionNode, ()> {
     let mut params = ASTVec::new();
@@ -24,6 +24,7 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<ExpressionNode, ()> {
 
         match current {
             Token::Number(num) => params.push(ASTNode::NumberLiteral(num)),
+            Token::Quantity(qty) => params.push(ASTNode::QuantityLiteral(qty.into())),
             Token::String(str) => params.push(ASTNode::StringLiteral(str)),
             Token::Symbol(sym) => {
                 if sym == Symbols::Equal {
@@ -84,6 +85,20 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<ExpressionNode, ()> {
                 let class_definition = class_definition::resolve(tokens)?;
                 params.push(ASTNode::ClassDefinition(class_definition.into()));
             }
+            Token::Keyword(Keyword::If) => {
+                // conditional expression:
+                // `if cond {a} elif cond {b} else {c}`
+                let if_node = statement::if_resolve(tokens)?;
+                params.push(ASTNode::ConditionalExpression(if_node.into()));
+            }
+            Token::Question => {
+                // conditional expression: `cond ? a : b`,
+                // the params before `?` are regarded as the condition.
+                let condition = ExpressionNode {
+                    elements: params_resolve(params)?,
+                };
+                return ternary_resolve(condition, tokens);
+            }
             Token::Keyword(Keyword::New) => {
                 // class instantiation
                 let instantiation_node = instantiation::resolve(tokens)?;
@@ -97,6 +112,95 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<ExpressionNode, ()> {
         }
     }
 
+    Ok(ExpressionNode {
+        elements: params_resolve(params)?,
+    })
+}
+
+fn ternary_resolve(condition: ExpressionNode, tokens: &mut TokenVec) -> Result<ExpressionNode, ()> {
+    // without Question
+    // template: `a : b`
+
+    let mut paren_count = 0;
+    let mut question_count = 0;
+    let mut sub_tokens = TokenVec::new();
+
+    loop {
+        let Some(current) = tokens.pop_front() else {
+            return Err(syntax_error("missing `:` for the conditional expression")?);
+        };
+
+        match current {
+            Token::Paren(Paren::LeftParen | Paren::LeftBracket | Paren::LeftBrace) => {
+                paren_count += 1
+            }
+            Token::Paren(Paren::RightParen | Paren::RightBracket | Paren::RightBrace) => {
+                paren_count -= 1
+            }
+            // the nested conditional expression
+            Token::Question if paren_count == 0 => question_count += 1,
+            Token::Colon if paren_count == 0 => {
+                if question_count == 0 {
+                    break;
+                }
+                question_count -= 1;
+            }
+            _ => {}
+        }
+        sub_tokens.push_back(current);
+    }
+
+    let consequent = resolve(&mut sub_tokens)?;
+    // the alternative ends with `)` or the end of tokens
+    let alternative = resolve(tokens)?;
+    if consequent.elements.is_empty() || alternative.elements.is_empty() {
+        return Err(syntax_error(
+            "missing value for the conditional expression",
+        )?);
+    }
+
+    let if_node = IfStatement {
+        condition,
+        body: vec![ASTNode::Expression(consequent.into())],
+        else_body: Some(vec![ASTNode::Expression(alternative.into())]),
+    };
+    Ok(ExpressionNode {
+        elements: vec![ASTNode::ConditionalExpression(if_node.into())],
+    })
+}
+
+// converts the params into RPN
+fn params_resolve(mut params: ASTVec) -> Result<ASTVec, ()> {
+    // the logical operators have the lowest priority,
+    // both operands are wrapped as sub-expression
+    // so that the right operand can be short-circuited.
+    for logical_symbol in [Symbols::Or, Symbols::And] {
+        let position = params.iter().rposition(
+            |node| matches!(node, ASTNode::SymbolLiteral(sym) if *sym == logical_symbol),
+        );
+        let Some(index) = position else {
+            continue;
+        };
+
+        let right_params = params.split_off(index + 1);
+        params.pop();
+        if params.is_empty() || right_params.is_empty() {
+            let msg = format!("operating value missing for `{}` operator", logical_symbol);
+            return Err(syntax_error(&msg)?);
+        }
+        let left = ExpressionNode {
+            elements: params_resolve(params)?,
+        };
+        let right = ExpressionNode {
+            elements: params_resolve(right_params)?,
+        };
+        return Ok(vec![
+            ASTNode::Expression(left.into()),
+            ASTNode::Expression(right.into()),
+            ASTNode::SymbolLiteral(logical_symbol),
+        ]);
+    }
+
     let mut symbol_stack = ASTVec::new();
     let mut result_stack = ASTVec::new();
 
@@ -106,11 +210,13 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<ExpressionNode, ()> {
             ASTNode::Variable(_)
             | ASTNode::Assignment(_)
             | ASTNode::NumberLiteral(_)
+            | ASTNode::QuantityLiteral(_)
             | ASTNode::StringLiteral(_)
             | ASTNode::ArrayLiteral(_)
             | ASTNode::Expression(_)
             | ASTNode::Invocation(_)
             | ASTNode::LazyExpression(_)
+            | ASTNode::ConditionalExpression(_)
             | ASTNode::Instantiation(_)
             | ASTNode::ObjectReading(_)
             | ASTNode::ImportStatement(_)
@@ -123,6 +229,15 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<ExpressionNode, ()> {
                     symbol_stack.push(node);
                     continue;
                 }
+                // the prefix operator is applied after its operand
+                if let ASTNode::SymbolLiteral(
+                    Symbols::LogicalNot | Symbols::BitNot | Symbols::Negate,
+                ) = node
+                {
+                    symbol_stack.push(node);
+                    continue;
+                }
+
                 let current_node = &node;
                 let mut last_node = symbol_stack.last().unwrap();
                 let mut priority = compare(current_node, last_node)?;
@@ -159,8 +274,5 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<ExpressionNode, ()> {
         let last_symbol_node = symbol_stack.pop().unwrap();
         result_stack.push(last_symbol_node);
     }
-
-    Ok(ExpressionNode {
-        elements: result_stack,
-    })
+    Ok(result_stack)
 }
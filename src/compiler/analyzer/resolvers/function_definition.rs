@@ -1,40 +1,101 @@
 use crate::compiler::tokenizer::token::{Token, TokenVec};
-use crate::public::compile_time::ast::types::FunctionDefinitionNode;
+use crate::public::compile_time::ast::types::{ExpressionNode, FunctionDefinitionNode};
 use crate::public::compile_time::parens::Paren;
 use crate::public::error::syntax_error;
 use crate::public::value::function::UserDefinedFnParam;
+use crate::public::value::symbols::Symbols;
 use crate::public::value::value::ValueType;
 
-use super::statement_block;
+use super::{expression, statement_block};
+
+fn default_resolve(tokens: &mut TokenVec) -> Result<ExpressionNode, ()> {
+    // template: `= value, ...` | `= value)`
+    let mut paren_count = 0;
+    let mut sub_tokens = TokenVec::new();
+
+    while let Some(current) = tokens.front() {
+        match current {
+            Token::Divider | Token::Paren(Paren::RightParen) if paren_count == 0 => break,
+            Token::Paren(Paren::LeftParen | Paren::LeftBracket | Paren::LeftBrace) => {
+                paren_count += 1
+            }
+            Token::Paren(Paren::RightParen | Paren::RightBracket | Paren::RightBrace) => {
+                paren_count -= 1
+            }
+            _ => {}
+        }
+        sub_tokens.push_back(tokens.pop_front().unwrap());
+    }
+
+    let default_node = expression::resolve(&mut sub_tokens)?;
+    if default_node.elements.is_empty() {
+        return Err(syntax_error("missing default value of function param")?);
+    }
+    Ok(default_node)
+}
 
 // refactor: params_resolve
 fn params_resolve(tokens: &mut TokenVec) -> Result<Vec<UserDefinedFnParam>, ()> {
     // structure:
-    // identi annotation) {function body ...}
+    // identi annotation = default, ...rest) {function body ...}
 
     let mut params = vec![];
 
     while let Some(current) = tokens.pop_front() {
         match current {
             Token::Identi(identi) => {
-                let Some(next) = tokens.pop_front() else {
+                let mut type__ = ValueType::Void;
+                if let Some(Token::Annotation(_)) = tokens.front() {
+                    let Some(Token::Annotation(annotation)) = tokens.pop_front() else {
+                        unreachable!()
+                    };
+                    type__ = annotation;
+                }
+
+                let mut default = None;
+                if tokens.front() == Some(&Token::Symbol(Symbols::Equal)) {
+                    tokens.pop_front();
+                    default = Some(default_resolve(tokens)?);
+                } else if params
+                    .iter()
+                    .any(|param: &UserDefinedFnParam| param.default.is_some())
+                {
                     return Err(syntax_error(
-                        "incompleted function definition",
+                        "required param should not follow the optional param",
                     )?);
-                };
-                if let Token::Annotation(type__) = next {
-                    params.push(UserDefinedFnParam { type__, identi })
-                } else if let Token::Divider | Token::Paren(Paren::RightParen) = next {
-                    tokens.push_front(next);
-                    params.push(UserDefinedFnParam {
-                        type__: ValueType::Void,
-                        identi,
-                    });
-                } else {
+                }
+
+                match tokens.front() {
+                    Some(Token::Divider | Token::Paren(Paren::RightParen)) => {}
+                    Some(_) => {
+                        return Err(syntax_error(
+                            "type annotation expected in function definition",
+                        )?)
+                    }
+                    None => return Err(syntax_error("incompleted function definition")?),
+                }
+                params.push(UserDefinedFnParam {
+                    type__,
+                    identi,
+                    default,
+                    is_rest: false,
+                })
+            }
+            Token::Ellipsis => {
+                // the rest param collects the extra arguments
+                let (Some(Token::Identi(identi)), Some(Token::Paren(Paren::RightParen))) =
+                    (tokens.pop_front(), tokens.front())
+                else {
                     return Err(syntax_error(
-                        "type annotation expected in function definition",
+                        "the rest param should be the last function param",
                     )?);
-                }
+                };
+                params.push(UserDefinedFnParam {
+                    type__: ValueType::Array,
+                    identi,
+                    default: None,
+                    is_rest: true,
+                })
             }
             Token::Divider => continue,
             Token::Paren(Paren::RightParen) => break,
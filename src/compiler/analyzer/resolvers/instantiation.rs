@@ -22,7 +22,7 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<InstantiationNode, ()> {
         )?);
     }
 
-    let instantiation_params = list::resolve(tokens, Paren::RightParen)?;
+    let (instantiation_params, named_params) = list::arguments_resolve(tokens, Paren::RightParen)?;
     // let instantiation_params =
     //     array::literal_resolve(tokens)?;
 
@@ -31,5 +31,6 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<InstantiationNode, ()> {
         params: ArrayLiteralNode {
             elements: instantiation_params,
         },
+        named_params,
     })
 }
@@ -1,10 +1,12 @@
 use crate::compiler::analyzer::resolvers::expression;
 use crate::compiler::tokenizer::token::{Token, TokenVec};
-use crate::public::compile_time::ast::types::ExpressionNode;
+use crate::public::compile_time::ast::types::{ExpressionNode, NamedParams};
 use crate::public::compile_time::parens::Paren;
 use crate::public::error::syntax_error;
+use crate::public::value::symbols::Symbols;
 
-pub fn resolve(tokens: &mut TokenVec, identi_paren: Paren) -> Result<Vec<ExpressionNode>, ()> {
+// splits the tokens of elements by divider
+fn split(tokens: &mut TokenVec, identi_paren: Paren) -> Result<Vec<TokenVec>, ()> {
     // examples:
     // 1, 2)
     // a, 1)
@@ -17,21 +19,15 @@ pub fn resolve(tokens: &mut TokenVec, identi_paren: Paren) -> Result<Vec<Express
         Outer,
     }
 
-    fn element_resolve(
-        sub_tokens: &mut TokenVec,
-        elements: &mut Vec<ExpressionNode>,
-    ) -> Result<(), ()> {
+    fn element_resolve(sub_tokens: &mut TokenVec, elements: &mut Vec<TokenVec>) {
         if sub_tokens.len() > 0 {
-            let element = expression::resolve(sub_tokens)?;
-            sub_tokens.clear();
-            elements.push(element);
+            elements.push(std::mem::take(sub_tokens));
         }
-        Ok(())
     }
 
     let mut state = State::Outer;
     let mut paren_count = 1;
-    let mut elements = Vec::<ExpressionNode>::new();
+    let mut elements = Vec::<TokenVec>::new();
     let mut sub_tokens = TokenVec::new();
 
     loop {
@@ -55,7 +51,7 @@ pub fn resolve(tokens: &mut TokenVec, identi_paren: Paren) -> Result<Vec<Express
             paren_count += 1;
         }
         if is_divider && (state == State::Outer) {
-            element_resolve(&mut sub_tokens, &mut elements)?;
+            element_resolve(&mut sub_tokens, &mut elements);
             continue;
         }
         if is_right_paren {
@@ -65,7 +61,7 @@ pub fn resolve(tokens: &mut TokenVec, identi_paren: Paren) -> Result<Vec<Express
             }
 
             if is_identi_paren && paren_count == 0 {
-                element_resolve(&mut sub_tokens, &mut elements)?;
+                element_resolve(&mut sub_tokens, &mut elements);
                 break;
             }
         }
@@ -75,3 +71,57 @@ pub fn resolve(tokens: &mut TokenVec, identi_paren: Paren) -> Result<Vec<Express
 
     Ok(elements)
 }
+
+pub fn resolve(tokens: &mut TokenVec, identi_paren: Paren) -> Result<Vec<ExpressionNode>, ()> {
+    let mut elements = vec![];
+    for mut element_tokens in split(tokens, identi_paren)? {
+        elements.push(expression::resolve(&mut element_tokens)?);
+    }
+    Ok(elements)
+}
+
+// resolves the invocation arguments,
+// returns the positional and the named arguments.
+pub fn arguments_resolve(
+    tokens: &mut TokenVec,
+    identi_paren: Paren,
+) -> Result<(Vec<ExpressionNode>, NamedParams), ()> {
+    // examples:
+    // 1, x = 2)
+    // name = "a", age = 3)
+
+    let mut params = vec![];
+    let mut named_params = NamedParams::new();
+
+    for mut element_tokens in split(tokens, identi_paren)? {
+        let is_named = matches!(
+            (element_tokens.front(), element_tokens.get(1)),
+            (Some(Token::Identi(_)), Some(Token::Symbol(Symbols::Equal)))
+        );
+        if !is_named {
+            if !named_params.is_empty() {
+                return Err(syntax_error(
+                    "positional argument should not follow the named argument",
+                )?);
+            }
+            params.push(expression::resolve(&mut element_tokens)?);
+            continue;
+        }
+
+        let Some(Token::Identi(param_name)) = element_tokens.pop_front() else {
+            unreachable!()
+        };
+        element_tokens.pop_front();
+        if named_params.iter().any(|(name, _)| *name == param_name) {
+            let msg = format!("duplicate named argument `{}`", param_name);
+            return Err(syntax_error(&msg)?);
+        }
+        let param_node = expression::resolve(&mut element_tokens)?;
+        if param_node.elements.is_empty() {
+            let msg = format!("missing value for named argument `{}`", param_name);
+            return Err(syntax_error(&msg)?);
+        }
+        named_params.push((param_name, param_node));
+    }
+    Ok((params, named_params))
+}
@@ -1,7 +1,8 @@
 use crate::compiler::tokenizer::token::{Token, TokenVec};
 use crate::public::compile_time::ast::ast_enum::ASTNode;
 use crate::public::compile_time::ast::types::{
-    ExpressionNode, ForStatement, IfStatement, ImportNode, ModuleType,
+    ExpressionNode, ForInStatement, ForStatement, IfStatement, ImportNode, ModuleType,
+    WhileStatement,
 };
 use crate::public::compile_time::parens::Paren;
 use crate::public::compile_time::{ast::types::StatementNode, keywords::Keyword};
@@ -25,6 +26,84 @@ fn statement_condition_resolve(tokens: &mut TokenVec) -> Result<ExpressionNode,
     Ok(expression::resolve(&mut sub_tokens)?)
 }
 
+fn for_resolve(tokens: &mut TokenVec) -> Result<StatementNode, ()> {
+    // `for item in iterable { ... }` |
+    // `for index, item in iterable { ... }`
+    let in_keyword = Some(&Token::Keyword(Keyword::In));
+    let in_index = match (tokens.front(), tokens.get(1), tokens.get(2)) {
+        (Some(Token::Identi(_)), next, _) if next == in_keyword => Some(1),
+        (Some(Token::Identi(_)), Some(Token::Divider), Some(Token::Identi(_)))
+            if tokens.get(3) == in_keyword =>
+        {
+            Some(3)
+        }
+        _ => None,
+    };
+
+    let Some(in_index) = in_index else {
+        // `for count { ... }`
+        return Ok(StatementNode::ForLoop(ForStatement {
+            loop_count: statement_condition_resolve(tokens)?,
+            body: statement_block::resolve(tokens)?,
+        }));
+    };
+
+    let mut names = tokens
+        .drain(..=in_index)
+        .filter_map(|token| match token {
+            Token::Identi(name) => Some(name),
+            _ => None,
+        })
+        .collect::<Vec<String>>();
+    let item_name = names.pop().unwrap();
+    let index_name = names.pop();
+
+    let iterable = statement_condition_resolve(tokens)?;
+    if iterable.elements.is_empty() {
+        return Err(syntax_error(
+            "iterable value missing for `for-in` statement",
+        )?);
+    }
+    Ok(StatementNode::ForInLoop(ForInStatement {
+        index_name,
+        item_name,
+        iterable,
+        body: statement_block::resolve(tokens)?,
+    }))
+}
+
+pub fn if_resolve(tokens: &mut TokenVec) -> Result<IfStatement, ()> {
+    let condition = statement_condition_resolve(tokens)?;
+    let body = statement_block::resolve(tokens)?;
+
+    // `elif` | `else if` | `else`
+    let else_body = match tokens.front() {
+        Some(Token::Keyword(Keyword::Elif)) => {
+            tokens.pop_front();
+            Some(vec![nested_if_resolve(tokens)?])
+        }
+        Some(Token::Keyword(Keyword::Else)) => {
+            tokens.pop_front();
+            match tokens.pop_front() {
+                Some(Token::Keyword(Keyword::If)) => Some(vec![nested_if_resolve(tokens)?]),
+                Some(Token::Paren(Paren::LeftBrace)) => Some(statement_block::resolve(tokens)?),
+                _ => return Err(syntax_error("`{` or `if` is expected following `else`")?),
+            }
+        }
+        _ => None,
+    };
+
+    Ok(IfStatement {
+        condition,
+        body,
+        else_body,
+    })
+}
+fn nested_if_resolve(tokens: &mut TokenVec) -> Result<ASTNode, ()> {
+    let if_statement = StatementNode::Condition(if_resolve(tokens)?);
+    Ok(ASTNode::Statement(if_statement.into()))
+}
+
 pub fn resolve(keyword: Keyword, tokens: &mut TokenVec) -> Result<StatementNode, ()> {
     // remove the keyword token
     tokens.pop_front();
@@ -34,14 +113,36 @@ pub fn resolve(keyword: Keyword, tokens: &mut TokenVec) -> Result<StatementNode,
             let output_expression = expression::resolve(tokens)?;
             StatementNode::Output(output_expression)
         }
-        Keyword::For => StatementNode::ForLoop(ForStatement {
-            loop_count: statement_condition_resolve(tokens)?,
-            body: statement_block::resolve(tokens)?,
-        }),
-        Keyword::If => StatementNode::Condition(IfStatement {
+        Keyword::For => for_resolve(tokens)?,
+        Keyword::While => StatementNode::WhileLoop(WhileStatement {
             condition: statement_condition_resolve(tokens)?,
             body: statement_block::resolve(tokens)?,
+            is_do_while: false,
         }),
+        Keyword::Do => {
+            // template: `do { ... } while cond`
+            if tokens.pop_front() != Some(Token::Paren(Paren::LeftBrace)) {
+                return Err(syntax_error("`{` is expected following `do`")?);
+            }
+            let body = statement_block::resolve(tokens)?;
+            if tokens.pop_front() != Some(Token::Keyword(Keyword::While)) {
+                return Err(syntax_error(
+                    "`while` is expected following the `do` block",
+                )?);
+            }
+            let condition = expression::resolve(tokens)?;
+            if condition.elements.is_empty() {
+                return Err(syntax_error(
+                    "loop condition missing for `do-while` statement",
+                )?);
+            }
+            StatementNode::WhileLoop(WhileStatement {
+                condition,
+                body,
+                is_do_while: true,
+            })
+        }
+        Keyword::If => StatementNode::Condition(if_resolve(tokens)?),
 
         Keyword::Import => {
             let Some(next_token) = tokens.pop_front() else {
@@ -70,6 +171,7 @@ pub fn resolve(keyword: Keyword, tokens: &mut TokenVec) -> Result<StatementNode,
         }
 
         Keyword::Break => StatementNode::Break(expression::resolve(tokens)?),
+        Keyword::Return => StatementNode::Return(expression::resolve(tokens)?),
         Keyword::Continue => StatementNode::Continue, // Do nothing
         _ => {
             // example:
@@ -1,6 +1,7 @@
 use crate::compiler::analyzer::resolvers::sequence;
 use crate::compiler::tokenizer::token::{Token, TokenVec};
 use crate::public::compile_time::ast::ast_enum::ASTVec;
+use crate::public::compile_time::keywords::Keyword;
 use crate::public::compile_time::parens::Paren;
 
 pub fn resolve(tokens: &mut TokenVec) -> Result<ASTVec, ()> {
@@ -37,7 +38,17 @@ pub fn resolve(tokens: &mut TokenVec) -> Result<ASTVec, ()> {
             state = State::Inner;
             paren_count += 1;
         }
-        if is_divider && (state == State::Outer) {
+        // the divider in `for index, item in ...`
+        // does not end the sequence
+        let is_for_in_divider = matches!(
+            (sub_tokens.front(), sub_tokens.get(1), sub_tokens.len()),
+            (
+                Some(Token::Keyword(Keyword::For)),
+                Some(Token::Identi(_)),
+                2
+            )
+        );
+        if is_divider && (state == State::Outer) && !is_for_in_divider {
             let sub_sequence_node = sequence::resolve(&mut sub_tokens)?;
             sub_tokens.clear();
             result_params.push(sub_sequence_node);
@@ -3,19 +3,34 @@ use crate::public::{
     error::{internal_error, InternalComponent},
 };
 
-const PRIORITY: [i8; 12] = [
-    1, // Symbols::Plus
-    1, // Symbols::Minus
-    2, // Symbols::Multiply
-    2, // Symbols::Divide
-    3, // Symbols::Power
-    5, // Symbols::Not
-    0, // Symbols::LessThan
-    0, // Symbols::MoreThan
-    0, // Symbols::LessThanEqual
-    0, // Symbols::MoreThanEqual
-    0, // Symbols::CompareEqual
-    0, // Symbols::NotEqual
+// C-like precedence, the greater binds tighter
+const PRIORITY: [i8; 26] = [
+    7,  // Symbols::Plus
+    7,  // Symbols::Minus
+    8,  // Symbols::Multiply
+    8,  // Symbols::Divide
+    8,  // Symbols::Modulo
+    8,  // Symbols::FloorDivide
+    9,  // Symbols::Power
+    8,  // Symbols::Negate
+    11, // Symbols::Not
+    4,  // Symbols::LessThan
+    4,  // Symbols::MoreThan
+    4,  // Symbols::LessThanEqual
+    4,  // Symbols::MoreThanEqual
+    4,  // Symbols::CompareEqual
+    4,  // Symbols::NotEqual
+    0,  // Symbols::LogicalNot
+    -1, // Symbols::And
+    -2, // Symbols::Or
+    3,  // Symbols::BitAnd
+    1,  // Symbols::BitOr
+    2,  // Symbols::BitXor
+    10, // Symbols::BitNot
+    6,  // Symbols::LeftShift
+    6,  // Symbols::RightShift
+    5,  // Symbols::Range
+    5,  // Symbols::RangeInclusive
 ];
 
 fn get_priority(symbol_node: &ASTNode) -> Result<i8, ()> {
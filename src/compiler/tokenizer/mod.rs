@@ -3,54 +3,172 @@ pub mod token;
 
 use std::str::Chars;
 
+use bigdecimal::BigDecimal;
+use num_bigint::BigInt;
+use num_complex::Complex64;
+
 use crate::compiler::tokenizer::char_converter::char_converter;
 use crate::public::compile_time::keywords::Keyword;
 use crate::public::compile_time::parens::Paren;
 use crate::public::error::{assignment_error, syntax_error};
+use crate::public::value::quantity::{Quantity, Unit};
 use crate::public::value::symbols::Symbols;
-use crate::public::value::{number::Number, value::ValueType};
-use crate::utils::ascii::{ascii_to_num, is_identi_ascii};
+use crate::public::value::{decimal, number::Number, value::ValueType};
+use crate::utils::ascii::is_identi_ascii;
 
 use token::{Token, TokenType, TokenVec};
 
-fn number_resolver(chars: &mut Chars, first_ch: char, index: &mut usize) -> (char, Number) {
+// returns the next char without consuming it
+fn peek(chars: &Chars) -> Option<char> {
+    chars.clone().next()
+}
+fn peek_nth(chars: &Chars, n: usize) -> Option<char> {
+    chars.clone().nth(n)
+}
+
+fn radix_resolver(chars: &mut Chars, radix: u32, index: &mut usize) -> Result<(char, Number), ()> {
+    let mut digits = String::new();
+    let mut cached_ch = '\0';
+
+    while let Some(ch) = chars.next() {
+        *index += 1;
+
+        if ch == '_' {
+            continue;
+        }
+        if !is_identi_ascii(ch) && !ch.is_ascii_digit() {
+            cached_ch = ch;
+            break;
+        }
+        if !ch.is_digit(radix) {
+            let msg = format!("invalid digit '{}' for base {} number literal", ch, radix);
+            return Err(syntax_error(&msg)?);
+        }
+        digits.push(ch);
+    }
+
+    let Some(value) = BigInt::parse_bytes(digits.as_bytes(), radix) else {
+        let msg = format!("missing digits for base {} number literal", radix);
+        return Err(syntax_error(&msg)?);
+    };
+    Ok((cached_ch, Number::from(value)))
+}
+
+// supported number literals:
+// 123, 1.5, 1_000_000, 1.5e-3, 6.02E23, 2i,
+// 0x1F, 0o17, 0b1010
+fn number_resolver(
+    chars: &mut Chars,
+    first_ch: char,
+    index: &mut usize,
+) -> Result<(char, Number), ()> {
+    if first_ch == '0' {
+        let radix = match peek(chars) {
+            Some('x' | 'X') => Some(16),
+            Some('o' | 'O') => Some(8),
+            Some('b' | 'B') => Some(2),
+            _ => None,
+        };
+        if let Some(radix) = radix {
+            // skip the radix prefix
+            chars.next();
+            *index += 1;
+            return radix_resolver(chars, radix, index);
+        }
+    }
+
     enum State {
         Int,
         Float,
+        Exponent,
     }
 
     let mut state = State::Int;
-    let mut float_para: f64 = 10.0;
-    let mut value = Number::Int(ascii_to_num(first_ch));
+    let mut literal = String::from(first_ch);
+    let mut is_imaginary = false;
     let mut cached_ch = '\0';
 
     while let Some(ch) = chars.next() {
         *index += 1;
 
         if ch.is_ascii_digit() {
-            let num_ascii = ascii_to_num(ch);
-            match state {
-                State::Int => {
-                    value = value * Number::Int(10);
-                    value = value + Number::Int(num_ascii);
+            literal.push(ch);
+            continue;
+        }
+
+        match (ch, &state) {
+            // digit separator, example: 1_000_000
+            ('_', _) => {
+                if !peek(chars).is_some_and(|next| next.is_ascii_digit()) {
+                    return Err(syntax_error("digit separator should be followed by digit")?);
+                }
+            }
+            ('.', State::Int) if peek(chars) == Some('.') => {
+                // range symbol, example: 1..5
+                cached_ch = ch;
+                break;
+            }
+            ('.', State::Int) => {
+                state = State::Float;
+                literal.push(ch);
+            }
+            ('e' | 'E', State::Int | State::Float) => {
+                // the exponent part: e3, e+3, e-3
+                let is_exponent = match peek(chars) {
+                    Some('+' | '-') => peek_nth(chars, 1).is_some_and(|next| next.is_ascii_digit()),
+                    Some(next) => next.is_ascii_digit(),
+                    None => false,
+                };
+                if !is_exponent {
+                    cached_ch = ch;
+                    break;
                 }
-                State::Float => {
-                    value = value + Number::Float((num_ascii as f64) / float_para);
-                    float_para *= 10.0;
+                state = State::Exponent;
+                literal.push(ch);
+                if let Some(sign @ ('+' | '-')) = peek(chars) {
+                    chars.next();
+                    *index += 1;
+                    literal.push(sign);
                 }
             }
-            continue;
+            ('i', _)
+                if !peek(chars)
+                    .is_some_and(|next| is_identi_ascii(next) || next.is_ascii_digit()) =>
+            {
+                // imaginary unit suffix, example: 2i, 0.5i,
+                // while `3in` is the quantity literal.
+                is_imaginary = true;
+                if let Some(next_ch) = chars.next() {
+                    *index += 1;
+                    cached_ch = next_ch;
+                }
+                break;
+            }
+            _ => {
+                cached_ch = ch;
+                break;
+            }
         }
+    }
 
-        if ch == '.' {
-            state = State::Float;
-            value = value.float();
-            continue;
+    if literal.ends_with('.') {
+        // example: 1. -> 1.0
+        literal.push('0');
+    }
+    let value = match state {
+        State::Int => Number::from(literal.parse::<BigInt>().unwrap()),
+        _ if decimal::is_enabled() => {
+            Number::Decimal(decimal::round(literal.parse::<BigDecimal>().unwrap()))
         }
-        cached_ch = ch;
-        break;
+        _ => Number::Float(literal.parse::<f64>().unwrap()),
+    };
+    if is_imaginary {
+        return Ok((
+            cached_ch,
+            Number::from(Complex64::new(0.0, value.float_value())),
+        ));
     }
-    return (cached_ch, value);
+    Ok((cached_ch, value))
 }
 
 fn identi_resolver(chars: &mut Chars, first_ch: char, index: &mut usize) -> (char, String) {
@@ -75,9 +193,6 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
     // check is in annotation state.
     let mut last_type = TokenType::Unknown;
 
-    // use to control if number is minus
-    let mut is_num_minus = false;
-
     let mut tokens = TokenVec::new();
 
     let mut chars = source.chars();
@@ -99,10 +214,11 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
         if ch.is_ascii_digit() {
             last_type = TokenType::Number;
             let mut value: Number;
-            (cached_ch, value) = number_resolver(&mut chars, ch, &mut index);
+            (cached_ch, value) = number_resolver(&mut chars, ch, &mut index)?;
 
-            if is_num_minus {
-                is_num_minus = false;
+            // the prefix minus of the number literal is folded
+            if tokens.back() == Some(&Token::Symbol(Symbols::Negate)) {
+                tokens.pop_back();
                 value = Number::Int(0) - value;
             }
 
@@ -115,6 +231,22 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
             let value: String;
             (cached_ch, value) = identi_resolver(&mut chars, ch, &mut index);
 
+            // quantity literal: number followed by unit, example: 3 km
+            if last_type == TokenType::Number {
+                if let (Some(Token::Number(_)), Some(unit)) = (tokens.back(), Unit::parse(&value)) {
+                    let Some(Token::Number(num)) = tokens.pop_back() else {
+                        unreachable!()
+                    };
+                    tokens.push_back(Token::Quantity(Quantity::new(num, unit)));
+                    continue;
+                }
+            }
+            if value == "not" {
+                // prefix logical negation
+                last_type = TokenType::Symbol;
+                tokens.push_back(Token::Symbol(Symbols::LogicalNot));
+                continue;
+            }
             if last_type == TokenType::Annotation {
                 // Type annotation
                 match ValueType::is_valid_type(&value) {
@@ -154,9 +286,7 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
 
             // Computing symbols
             '+' => {
-                if last_type == TokenType::Symbol || last_type == TokenType::Unknown {
-                    is_num_minus = false;
-                } else {
+                if last_type != TokenType::Symbol && last_type != TokenType::Unknown {
                     last_type = TokenType::Symbol;
                     tokens.push_back(Token::Symbol(Symbols::Plus));
                 }
@@ -165,20 +295,56 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
                 let last_token = tokens.back();
                 if last_type == TokenType::Unknown
                     || last_type == TokenType::Symbol
+                    || last_type == TokenType::Keyword
                     || last_token == Some(&Token::Paren(Paren::LeftParen))
                     || last_token == Some(&Token::Paren(Paren::LeftBrace))
                     || last_token == Some(&Token::Paren(Paren::LeftBracket))
                 {
-                    is_num_minus = true;
+                    last_type = TokenType::Symbol;
+                    tokens.push_back(Token::Symbol(Symbols::Negate));
                 } else {
                     last_type = TokenType::Symbol;
                     tokens.push_back(Token::Symbol(Symbols::Minus));
                 }
             }
-            '*' | '/' | '^' | '!' | '<' | '>' => {
+            '/' if tokens.back() == Some(&Token::Symbol(Symbols::Divide)) => {
+                // `//`: floored division
+                tokens.pop_back();
+                tokens.push_back(Token::Symbol(Symbols::FloorDivide));
+            }
+            '&' | '|' | '<' | '>' if tokens.back() == Some(&Token::Symbol(Symbols::from(ch))) => {
+                // `&&`, `||`, `<<` and `>>`
+                tokens.pop_back();
+                let symbol = match ch {
+                    '&' => Symbols::And,
+                    '|' => Symbols::Or,
+                    '<' => Symbols::LeftShift,
+                    _ => Symbols::RightShift,
+                };
+                tokens.push_back(Token::Symbol(symbol));
+            }
+            '*' | '/' | '%' | '^' | '!' | '<' | '>' | '&' | '|' => {
                 last_type = TokenType::Symbol;
                 tokens.push_back(Token::Symbol(Symbols::from(ch)));
             }
+            '~' => {
+                // prefix `~` is bitwise not, infix `~` is bitwise xor
+                let last_token = tokens.back();
+                let is_prefix = last_type == TokenType::Unknown
+                    || last_type == TokenType::Symbol
+                    || last_type == TokenType::Keyword
+                    || last_token == Some(&Token::Paren(Paren::LeftParen))
+                    || last_token == Some(&Token::Paren(Paren::LeftBrace))
+                    || last_token == Some(&Token::Paren(Paren::LeftBracket));
+
+                last_type = TokenType::Symbol;
+                let symbol = if is_prefix {
+                    Symbols::BitNot
+                } else {
+                    Symbols::BitXor
+                };
+                tokens.push_back(Token::Symbol(symbol));
+            }
             '=' => {
                 if tokens.len() == 0 {
                     return Err(assignment_error("left-hand value missing")?);
@@ -190,8 +356,8 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
                 if let Token::Symbol(last_symbol) = last_token {
                     // if last_symbol
                     if Symbols::is_basic_symbol(last_symbol) {
-                        // if last char is: +  -  *  /  ^  !  >  <  =,
-                        // convert it to  : += -= *= /= ^= != >= <= ==.
+                        // if last char is: +  -  *  /  ^  !  >  <  =  ..,
+                        // convert it to  : += -= *= /= ^= != >= <= == ..=.
                         let target_symbol = Symbols::Equal.combine(last_symbol)?;
                         tokens.push_back(Token::Symbol(target_symbol));
                         continue;
@@ -238,11 +404,29 @@ pub fn tokenize(source: &String) -> Result<TokenVec, ()> {
                 last_type = TokenType::Symbol;
                 tokens.push_back(Token::Divider);
             }
+            '?' => {
+                last_type = TokenType::Symbol;
+                tokens.push_back(Token::Question);
+            }
+            ':' => {
+                last_type = TokenType::Symbol;
+                tokens.push_back(Token::Colon);
+            }
             '$' => {
                 // type annotation
                 last_type = TokenType::Annotation;
             }
 
+            '.' if tokens.back() == Some(&Token::Symbol(Symbols::Range)) => {
+                // `...`: rest param
+                tokens.pop_back();
+                tokens.push_back(Token::Ellipsis);
+            }
+            '.' if tokens.back() == Some(&Token::Symbol(Symbols::ObjectReading)) => {
+                // `..`: range
+                tokens.pop_back();
+                tokens.push_back(Token::Symbol(Symbols::Range));
+            }
             '.' => {
                 last_type = TokenType::Symbol;
                 tokens.push_back(Token::Symbol(Symbols::ObjectReading));
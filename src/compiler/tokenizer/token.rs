@@ -4,6 +4,7 @@ use std::fmt;
 use crate::public::compile_time::keywords::Keyword;
 use crate::public::compile_time::parens::Paren;
 use crate::public::value::number::Number;
+use crate::public::value::quantity::Quantity;
 use crate::public::value::symbols::Symbols;
 use crate::public::value::value::ValueType;
 
@@ -26,6 +27,7 @@ pub enum TokenType {
 #[derive(PartialEq, Clone)]
 pub enum Token {
     Number(Number),
+    Quantity(Quantity),
     String(String),
     Symbol(Symbols),
     Paren(Paren),
@@ -34,6 +36,13 @@ pub enum Token {
 
     Divider,
     Annotation(ValueType),
+
+    // the conditional expression: `cond ? a : b`
+    Question,
+    Colon,
+
+    // the rest param: `...rest`
+    Ellipsis,
 }
 pub type TokenVec = VecDeque<Token>;
 
@@ -41,13 +50,17 @@ impl fmt::Display for Token {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let token_display = match self {
             Token::Number(num) => format!("Number: {}", num),
+            Token::Quantity(qty) => format!("Quantity: {}", qty),
             Token::String(str) => format!("String: {}", str),
             Token::Symbol(sym) => format!("Symbol: {}", sym),
             Token::Paren(par) => format!("Paren: {:#?}", par),
             Token::Identi(ide) => format!("Identifier: {}", ide),
             Token::Keyword(key) => format!("Keywords: {}", key),
-            Token::Divider => format!("Divider"),
+            Token::Divider => "Divider".to_string(),
             Token::Annotation(type__) => format!("Annotation: {}", type__),
+            Token::Question => "Question".to_string(),
+            Token::Colon => "Colon".to_string(),
+            Token::Ellipsis => "Ellipsis".to_string(),
         };
         write!(f, "Token({})", token_display)
     }
@@ -1,17 +1,58 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use crate::public::compile_time::ast::types::ClassDefinitionNode;
+use crate::public::error::type_error;
+use crate::public::run_time::scope::{LocalScope, Scope};
 use crate::public::value::function::Function;
 use crate::public::value::oop::class::Class;
+use crate::public::value::value::{Value, ValueType};
 
 use super::function_definition;
 
-pub fn resolve(node: &ClassDefinitionNode) -> Result<Class, ()> {
+pub fn resolve(node: &ClassDefinitionNode, scope: &Scope) -> Result<Class, ()> {
+    let parent_class = match &node.parent {
+        Some(parent_name) => {
+            let parent_value = scope.read_var(parent_name)?;
+            let Value::Class(parent_class) = parent_value else {
+                return Err(type_error(
+                    Some("class inheritance"),
+                    vec![ValueType::Class],
+                    parent_value.get_type(),
+                )?);
+            };
+            Some(parent_class)
+        }
+        None => None,
+    };
+
+    // the methods of child class can access
+    // the parent class with the variable `super`
+    let super_scope = parent_class.as_ref().map(|parent_class| {
+        let mut super_scope = LocalScope::init();
+        super_scope
+            .variables
+            .insert(String::from("super"), Value::Class(parent_class.clone()));
+        super_scope.parent = scope.local.clone();
+        Rc::new(RefCell::new(super_scope))
+    });
+
     let mut method_stack = Vec::<(String, Function)>::new();
     for function_node in &node.method_nodes {
-        let function_def = function_definition::resolve(function_node)?;
+        let mut function_def = function_definition::resolve(function_node, scope)?;
+        if super_scope.is_some() {
+            function_def.closure = super_scope.clone();
+        }
         method_stack.push((
             function_node.name.clone().unwrap(),
             Function::from(function_def),
         ));
     }
-    return Ok(Class::new(node.properties.clone(), method_stack));
+
+    let properties = node.properties.clone();
+    let class = match parent_class {
+        Some(parent_class) => Class::inherit(parent_class, properties, method_stack),
+        None => Class::new(properties, method_stack),
+    };
+    return Ok(class);
 }
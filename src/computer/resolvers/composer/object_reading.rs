@@ -13,6 +13,12 @@ pub fn assign(obj_value: Value, property: &String, value: Value) -> Result<(), (
 }
 
 pub fn resolve(obj_value: Value, property: &String) -> Result<Value, ()> {
+    // the method reading from class, example: `super.method`
+    if let Value::Class(cls) = &obj_value {
+        let method = cls.get_method(property)?;
+        return Ok(Value::Function(method));
+    }
+
     let Value::Object(obj_ref) =
         obj_value else {
         println!("Invalid object reading.");
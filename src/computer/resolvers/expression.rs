@@ -2,12 +2,16 @@ use crate::public::compile_time::ast::ast_enum::ASTNode;
 use crate::public::compile_time::ast::types::{ExpressionNode, ModuleType};
 use crate::public::error::{internal_error, syntax_error, type_error, InternalComponent};
 use crate::public::run_time::scope::Scope;
+use crate::public::std::modules::bit_ops::BitOpsFn;
+use crate::public::value::number::Number;
 use crate::public::value::symbols::Symbols;
 use crate::public::value::value::{Value, ValueType, VoidSign};
 
 use super::class_definition;
-use super::operate::operate;
-use super::{array_literal, assignment, composer::compose, function_definition, instantiation};
+use super::operate::{object_operate, operate};
+use super::{
+    array_literal, assignment, composer::compose, function_definition, instantiation, statement,
+};
 
 pub fn resolve(node: &ExpressionNode, scope: &mut Scope) -> Result<Value, ()> {
     let elements = &node.elements;
@@ -17,17 +21,35 @@ pub fn resolve(node: &ExpressionNode, scope: &mut Scope) -> Result<Value, ()> {
 
     let mut value_stack = Vec::<Value>::new();
 
-    for current_node in elements {
+    let mut node_iter = elements.iter().peekable();
+    while let Some(current_node) = node_iter.next() {
+        // short-circuit: the right operand of `&&` and `||` is skipped
+        // when the left operand has determined the result.
+        if let Some(ASTNode::SymbolLiteral(sym @ (Symbols::And | Symbols::Or))) = node_iter.peek() {
+            if let Some(left_value) = value_stack.last() {
+                let left_bool = left_value.get_bool();
+                if left_bool == (*sym == Symbols::Or) {
+                    value_stack.pop();
+                    value_stack.push(Value::Boolean(left_bool));
+                    node_iter.next();
+                    continue;
+                }
+            }
+        }
+
         let current_value = match current_node {
             ASTNode::Expression(node) => resolve(node, scope)?,
 
             ASTNode::NumberLiteral(num) => Value::Number(num.clone()),
+            ASTNode::QuantityLiteral(qty) => Value::Quantity(qty.as_ref().clone()),
             ASTNode::StringLiteral(str) => Value::from(str.clone()),
 
             ASTNode::LazyExpression(node) => {
                 Value::LazyExpression(node.sub_sequence.clone().into())
             }
 
+            ASTNode::ConditionalExpression(node) => statement::condition_resolve(node, scope)?,
+
             ASTNode::ImportStatement(node) => {
                 if node.type__ == ModuleType::UserDefined {
                     scope.import_from_path(&node.target)?
@@ -35,8 +57,10 @@ pub fn resolve(node: &ExpressionNode, scope: &mut Scope) -> Result<Value, ()> {
                     unreachable!()
                 }
             }
-            ASTNode::FunctionDefinition(node) => Value::from(function_definition::resolve(node)?),
-            ASTNode::ClassDefinition(node) => Value::from(class_definition::resolve(node)?),
+            ASTNode::FunctionDefinition(node) => {
+                Value::from(function_definition::resolve(node, scope)?)
+            }
+            ASTNode::ClassDefinition(node) => Value::from(class_definition::resolve(node, scope)?),
 
             ASTNode::SymbolLiteral(sym) => {
                 if *sym == Symbols::Not {
@@ -58,6 +82,39 @@ pub fn resolve(node: &ExpressionNode, scope: &mut Scope) -> Result<Value, ()> {
                             "operating number is missing for Not operator",
                         )?);
                     }
+                } else if *sym == Symbols::LogicalNot {
+                    let Some(val) = value_stack.pop() else {
+                        return Err(syntax_error(
+                            "operating value is missing for `not` operator",
+                        )?);
+                    };
+                    Value::Boolean(!val.get_bool())
+                } else if *sym == Symbols::Negate {
+                    let Some(val) = value_stack.pop() else {
+                        return Err(syntax_error("operating value is missing for `-` operator")?);
+                    };
+                    // `-x` is computed as `-1 * x`
+                    let minus_one = Value::Number(Number::Int(-1));
+                    match val {
+                        Value::Object(_) => {
+                            object_operate(minus_one, val, Symbols::Multiply, scope)?
+                        }
+                        _ => operate(minus_one, val, Symbols::Multiply)?,
+                    }
+                } else if *sym == Symbols::BitNot {
+                    let Some(val) = value_stack.pop() else {
+                        return Err(syntax_error(
+                            "operating number is missing for `~` operator",
+                        )?);
+                    };
+                    let Value::Number(num) = val else {
+                        return Err(type_error(
+                            Some("`~` operator"),
+                            vec![ValueType::Number],
+                            val.get_type(),
+                        )?);
+                    };
+                    Value::Number(BitOpsFn::NOT.compute(&num, &Number::Int(0))?)
                 } else {
                     if value_stack.len() < 2 {
                         // no enough value for operating
@@ -69,7 +126,19 @@ pub fn resolve(node: &ExpressionNode, scope: &mut Scope) -> Result<Value, ()> {
                     let num2 = value_stack.pop().unwrap();
                     let num1 = value_stack.pop().unwrap();
                     let current_symbol = *sym;
-                    operate(num1, num2, current_symbol)?
+                    match current_symbol {
+                        // the left operand is not short-circuited here
+                        Symbols::And | Symbols::Or => Value::Boolean(num2.get_bool()),
+                        // the operator overloading for objects
+                        _ if matches!(
+                            (&num1, &num2),
+                            (Value::Object(_), _) | (_, Value::Object(_))
+                        ) =>
+                        {
+                            object_operate(num1, num2, current_symbol, scope)?
+                        }
+                        _ => operate(num1, num2, current_symbol)?,
+                    }
                 }
             }
             ASTNode::ArrayLiteral(node) => Value::from(array_literal::resolve(node, scope)?),
@@ -1,9 +1,12 @@
 use crate::public::compile_time::ast::types::FunctionDefinitionNode;
+use crate::public::run_time::scope::Scope;
 use crate::public::value::function::UserDefinedFunction;
 
-pub fn resolve(node: &FunctionDefinitionNode) -> Result<UserDefinedFunction, ()> {
+pub fn resolve(node: &FunctionDefinitionNode, scope: &Scope) -> Result<UserDefinedFunction, ()> {
     Ok(UserDefinedFunction {
         params: node.params.clone(),
         body: node.body.clone(),
+        // captures the current local scope
+        closure: scope.local.clone(),
     })
 }
@@ -1,11 +1,12 @@
-use crate::public::compile_time::ast::types::InstantiationNode;
-use crate::public::error::type_error;
+use crate::public::compile_time::ast::types::{InstantiationNode, NamedParams};
+use crate::public::error::{reference_error, syntax_error, type_error, ReferenceType};
 use crate::public::run_time::scope::Scope;
+use crate::public::value::array::ArrayLiteral;
 use crate::public::value::oop::class::Class;
 use crate::public::value::oop::object::Object;
 use crate::public::value::value::{Value, ValueType};
 
-use super::array_literal;
+use super::{array_literal, expression};
 
 pub fn resolve(node: &InstantiationNode, scope: &mut Scope) -> Result<Object, ()> {
     let target_class_value = scope.read_var(&node.class)?;
@@ -18,6 +19,57 @@ pub fn resolve(node: &InstantiationNode, scope: &mut Scope) -> Result<Object, ()
         )?)
     };
 
-    let instantiation_params = array_literal::resolve(&node.params, scope)?;
+    let mut instantiation_params = array_literal::resolve(&node.params, scope)?;
+    if !node.named_params.is_empty() {
+        named_params_resolve(
+            &node.named_params,
+            &target_class,
+            &mut instantiation_params,
+            scope,
+        )?;
+    }
     Class::instantiate(target_class.clone(), instantiation_params)
 }
+
+// puts the named params into the position of the matched properties
+fn named_params_resolve(
+    named_params: &NamedParams,
+    target_class: &Class,
+    instantiation_params: &mut ArrayLiteral,
+    scope: &mut Scope,
+) -> Result<(), ()> {
+    let property_names = target_class.property_names();
+    let positional_count = instantiation_params.len();
+
+    let mut named_values = vec![None; property_names.len()];
+    for (name, node) in named_params {
+        let Some(index) = property_names.iter().position(|identi| identi == name) else {
+            return Err(reference_error(ReferenceType::Property, name)?);
+        };
+        if index < positional_count {
+            return Err(syntax_error(&format!(
+                "property `{}` is assigned more than once",
+                name
+            ))?);
+        }
+        named_values[index] = Some(expression::resolve(node, scope)?);
+    }
+
+    // the properties after the last assigned one can be omitted
+    let assigned_count = named_values
+        .iter()
+        .rposition(|value| value.is_some())
+        .map_or(0, |index| index + 1);
+    for index in positional_count..assigned_count {
+        match named_values[index].take() {
+            Some(value) => instantiation_params.push_back(value),
+            None => {
+                return Err(syntax_error(&format!(
+                    "property `{}` is missing in class instantiation",
+                    property_names[index]
+                ))?)
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,5 +1,8 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use crate::computer::resolvers::expression;
-use crate::public::compile_time::ast::types::ExpressionNode;
+use crate::public::compile_time::ast::types::{ExpressionNode, NamedParams};
 use crate::public::run_time::build_in::BuildInFnIdenti;
 use crate::public::run_time::scope::{LocalScope, Scope};
 use crate::public::std::modules::BuildInFnCall;
@@ -14,21 +17,24 @@ fn call(function: &BuildInFunction, scope: &mut Scope) -> Result<Value, ()> {
         BuildInFnIdenti::String(str_fn) => str_fn.call(scope),
         BuildInFnIdenti::FileSystem(fs_fn) => fs_fn.call(scope),
         BuildInFnIdenti::BitOps(b_ops_fn) => b_ops_fn.call(scope),
+        BuildInFnIdenti::Sym(sym_fn) => sym_fn.call(scope),
     }
 }
 
 pub fn invoke(
     function: &BuildInFunction,
     params: &Vec<ExpressionNode>,
+    named_params: &NamedParams,
     scope: &mut Scope,
 ) -> Result<Value, ()> {
-    let mut local_scope = LocalScope::init();
+    let local_scope = Rc::new(RefCell::new(LocalScope::init()));
 
     Function::param_check(
         &function.params,
         params,
+        named_params,
         scope,
-        &mut local_scope,
+        &local_scope,
         expression::resolve,
     )?;
 
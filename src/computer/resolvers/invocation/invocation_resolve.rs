@@ -2,7 +2,7 @@ use std::borrow::Borrow;
 
 use crate::computer::resolvers::composer::compose;
 use crate::public::compile_time::ast::ast_enum::ASTNode;
-use crate::public::compile_time::ast::types::{ExpressionNode, InvocationNode};
+use crate::public::compile_time::ast::types::InvocationNode;
 use crate::public::error::{syntax_error, type_error};
 use crate::public::run_time::scope::Scope;
 use crate::public::value::function::Function;
@@ -12,27 +12,28 @@ use super::{build_in_function, lazy_expression, user_defined_function};
 
 fn variable_invoke(
     fn_name: &String,
-    params: &Vec<ExpressionNode>,
+    node: &InvocationNode,
     scope: &mut Scope,
 ) -> Result<Value, ()> {
     let fn_value = scope.read_var(fn_name)?;
-    let result = function_invoke(fn_value, params, scope)?;
+    let result = function_invoke(fn_value, node, scope)?;
     return Ok(result);
 }
 
 fn function_invoke(
     function_value: Value,
-    params: &Vec<ExpressionNode>,
+    node: &InvocationNode,
     scope: &mut Scope,
 ) -> Result<Value, ()> {
+    let (params, named_params) = (&node.params, &node.named_params);
     let invoke_result = match function_value {
         Value::LazyExpression(le) => lazy_expression::invoke(le, scope)?,
         Value::Function(fn_enum) => match fn_enum {
             Function::BuildIn(build_in_fn) => {
-                build_in_function::invoke(build_in_fn.borrow(), params, scope)?
+                build_in_function::invoke(build_in_fn.borrow(), params, named_params, scope)?
             }
             Function::UserDefined(user_defined_fn) => {
-                user_defined_function::invoke(&user_defined_fn, params, scope)?
+                user_defined_function::invoke(&user_defined_fn, params, named_params, scope)?
             }
         },
         _ => {
@@ -47,14 +48,12 @@ fn function_invoke(
 }
 
 pub fn resolve(node: &InvocationNode, scope: &mut Scope) -> Result<Value, ()> {
-    let params = &node.params;
-
     let fn_result = match &node.caller {
-        ASTNode::Variable(sub_node) => variable_invoke(&sub_node.name, params, scope)?,
+        ASTNode::Variable(sub_node) => variable_invoke(&sub_node.name, node, scope)?,
         ASTNode::Invocation(_) | ASTNode::ObjectReading(_) | ASTNode::ArrayElementReading(_) => {
             let caller_node = &node.caller;
             let function_value = compose::resolve(caller_node, scope)?;
-            function_invoke(function_value, params, scope)?
+            function_invoke(function_value, node, scope)?
         }
         _ => return Err(syntax_error("invalid callable target")?),
     };
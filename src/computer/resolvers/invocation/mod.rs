@@ -1,4 +1,4 @@
 mod build_in_function;
 pub mod invocation_resolve;
 mod lazy_expression;
-mod user_defined_function;
+pub mod user_defined_function;
@@ -1,5 +1,9 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use crate::computer::resolvers::{expression, sequence};
-use crate::public::compile_time::ast::types::ExpressionNode;
+use crate::public::compile_time::ast::types::{ExpressionNode, NamedParams};
+use crate::public::error::syntax_error;
 use crate::public::run_time::scope::{LocalScope, Scope};
 use crate::public::value::function::{Function, UserDefinedFunction};
 use crate::public::value::value::{Value, VoidSign};
@@ -8,8 +12,13 @@ fn call(function: &UserDefinedFunction, scope: &mut Scope) -> Result<Value, ()>
     for node in &function.body {
         let sequence_result = sequence::resolve(node, scope)?;
 
-        if let Value::Void(VoidSign::Break(val)) = sequence_result {
-            return Ok(val.unwrap());
+        match sequence_result {
+            Value::Void(VoidSign::Return(val)) => return Ok(val.unwrap()),
+            // `brk` only stops the loop
+            Value::Void(VoidSign::Break(_)) => {
+                return Err(syntax_error("`brk` outside of loop, use `return` instead")?)
+            }
+            _ => {}
         }
     }
 
@@ -19,26 +28,64 @@ fn call(function: &UserDefinedFunction, scope: &mut Scope) -> Result<Value, ()>
 pub fn invoke(
     function: &UserDefinedFunction,
     params: &Vec<ExpressionNode>,
+    named_params: &NamedParams,
     scope: &mut Scope,
 ) -> Result<Value, ()> {
     let mut local_scope = LocalScope::init();
+    local_scope.parent = function.closure.clone();
+    let local_scope = Rc::new(RefCell::new(local_scope));
 
     Function::param_check(
         &function.params,
         params,
+        named_params,
         scope,
-        &mut local_scope,
+        &local_scope,
         expression::resolve,
     )?;
 
+    scoped_call(function, local_scope, scope)
+}
+
+// invokes the function with the computed param values,
+// used by the operator overloading methods.
+pub fn invoke_with_values(
+    function: &UserDefinedFunction,
+    values: Vec<Value>,
+    scope: &mut Scope,
+) -> Result<Value, ()> {
+    let mut local_scope = LocalScope::init();
+    local_scope.parent = function.closure.clone();
+    let local_scope = Rc::new(RefCell::new(local_scope));
+
+    Function::param_bind(
+        &function.params,
+        values,
+        vec![],
+        scope,
+        &local_scope,
+        expression::resolve,
+    )?;
+
+    scoped_call(function, local_scope, scope)
+}
+
+fn scoped_call(
+    function: &UserDefinedFunction,
+    local_scope: Rc<RefCell<LocalScope>>,
+    scope: &mut Scope,
+) -> Result<Value, ()> {
     // cached local scope
     let mut local_scope_cached = scope.local.take();
 
     // assign new scope
     scope.local = Some(local_scope);
-    let fn_result = call(&function, scope)?;
+    let fn_result = call(&function, scope);
 
-    scope.local = local_scope_cached.take();
+    let local_scope = std::mem::replace(&mut scope.local, local_scope_cached.take());
+    if let Some(local_scope) = local_scope {
+        LocalScope::release(local_scope);
+    }
 
-    Ok(fn_result)
+    fn_result
 }
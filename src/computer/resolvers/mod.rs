@@ -14,3 +14,4 @@ mod composer;
 mod invocation;
 
 mod operate;
+pub mod to_string_hook;
@@ -1,29 +1,105 @@
-use crate::public::error::{internal_error, syntax_error, InternalComponent};
+use std::cmp::Ordering;
+
+use crate::public::error::{internal_error, math_error, syntax_error, InternalComponent};
+use crate::public::run_time::scope::Scope;
+use crate::public::std::modules::bit_ops::BitOpsFn;
+use crate::public::value::function::Function;
+use crate::public::value::number::Number;
+use crate::public::value::quantity::Quantity;
+use crate::public::value::range::Range;
 use crate::public::value::symbols::Symbols;
 use crate::public::value::value::Value;
 
+use super::invocation::user_defined_function;
+use super::to_string_hook;
+
 pub fn operate(val1: Value, val2: Value, operator: Symbols) -> Result<Value, ()> {
     let result = if let (Value::Number(num1_ref), Value::Number(num2_ref)) = (&val1, &val2) {
         // operating value must be type of Number.
-        let num1 = *num1_ref;
-        let num2 = *num2_ref;
+        let num1 = num1_ref.clone();
+        let num2 = num2_ref.clone();
         match operator {
+            Symbols::LessThan
+            | Symbols::MoreThan
+            | Symbols::LessThanEqual
+            | Symbols::MoreThanEqual
+                if num1.is_complex() || num2.is_complex() =>
+            {
+                return Err(math_error("complex numbers can not be compared")?);
+            }
+
             Symbols::Plus => Value::Number(num1 + num2),
             Symbols::Minus => Value::Number(num1 - num2),
             Symbols::Multiply => Value::Number(num1 * num2),
-            Symbols::Divide => Value::Number(num1 / num2),
+            Symbols::Divide => {
+                // the native float division results in infinity
+                let is_float_div = matches!(num1, Number::Float(_) | Number::NotANumber)
+                    || matches!(num2, Number::Float(_));
+                if num2.is_zero() && !is_float_div {
+                    return Err(math_error("the divisor should not be ZERO")?);
+                }
+                Value::Number(num1 / num2)
+            }
+            Symbols::Modulo | Symbols::FloorDivide => {
+                if num1.is_complex() || num2.is_complex() {
+                    return Err(math_error("complex numbers can not be floored")?);
+                }
+                // unlike `/`, there is no infinity to result in
+                if num2.is_zero() {
+                    return Err(math_error("the divisor should not be ZERO")?);
+                }
+                if operator == Symbols::Modulo {
+                    Value::Number(num1.modulo(num2))
+                } else {
+                    Value::Number(num1.floor_div(num2))
+                }
+            }
             Symbols::Power => Value::Number(num1.pow(num2)),
+            Symbols::BitAnd
+            | Symbols::BitOr
+            | Symbols::BitXor
+            | Symbols::LeftShift
+            | Symbols::RightShift => {
+                let bit_ops_fn = match operator {
+                    Symbols::BitAnd => BitOpsFn::AND,
+                    Symbols::BitOr => BitOpsFn::OR,
+                    Symbols::BitXor => BitOpsFn::XOR,
+                    Symbols::LeftShift => BitOpsFn::LShift,
+                    _ => BitOpsFn::RShift,
+                };
+                Value::Number(bit_ops_fn.compute(&num1, &num2)?)
+            }
             Symbols::LessThan => Value::Boolean(num1 < num2),
             Symbols::MoreThan => Value::Boolean(num1 > num2),
             Symbols::NotEqual => Value::Boolean(num1 != num2),
             Symbols::CompareEqual => Value::Boolean(num1 == num2),
             Symbols::LessThanEqual => Value::Boolean(num1 <= num2),
             Symbols::MoreThanEqual => Value::Boolean(num1 >= num2),
+            Symbols::Range | Symbols::RangeInclusive => {
+                let is_inclusive = operator == Symbols::RangeInclusive;
+                Value::from(Range::new(num1, num2, is_inclusive)?)
+            }
             _ => {
                 let msg = format!("unexpected symbol `{}` for operating", operator);
                 return Err(internal_error(InternalComponent::Computer, &msg)?);
             }
         }
+    } else if let (Value::Range(range), Value::Number(step)) = (&val1, &val2) {
+        // the range step: `start..end..step`
+        if operator != Symbols::Range {
+            return Err(syntax_error("invalid range operating")?);
+        }
+        Value::from(range.with_step(step.clone())?)
+    } else if let (Value::String(_), Value::Number(_)) | (Value::Number(_), Value::String(_)) =
+        (&val1, &val2)
+    {
+        // the number is concatenated in the current output format
+        if operator != Symbols::Plus {
+            return Err(syntax_error("invalid string operating")?);
+        }
+        Value::from(val1.to_raw_string() + &val2.to_raw_string())
+    } else if let (Value::Quantity(_), _) | (_, Value::Quantity(_)) = (&val1, &val2) {
+        quantity_operate(val1, val2, operator)?
     } else if let (Value::String(str1_ref), Value::String(str2_ref)) = (&val1, &val2) {
         let str1 = str1_ref.as_ref().borrow();
         let str2 = str2_ref.as_ref().borrow();
@@ -49,3 +125,140 @@ pub fn operate(val1: Value, val2: Value, operator: Symbols) -> Result<Value, ()>
     };
     return Ok(result);
 }
+
+fn quantity_operate(val1: Value, val2: Value, operator: Symbols) -> Result<Value, ()> {
+    // number is regarded as dimensionless quantity
+    fn to_quantity(val: Value) -> Result<Quantity, ()> {
+        match val {
+            Value::Quantity(qty) => Ok(qty),
+            Value::Number(num) => Ok(Quantity::from(num)),
+            _ => Err(syntax_error("invalid quantity operating")?),
+        }
+    }
+
+    if operator == Symbols::Power {
+        let (Value::Quantity(qty), Value::Number(exp)) = (val1, val2) else {
+            return Err(syntax_error("invalid quantity operating")?);
+        };
+        return Ok(qty.pow(exp)?.into_value());
+    }
+
+    let (qty1, qty2) = (to_quantity(val1)?, to_quantity(val2)?);
+    let result = match operator {
+        Symbols::Plus => qty1.add(qty2)?.into_value(),
+        Symbols::Minus => qty1.sub(qty2)?.into_value(),
+        Symbols::Multiply => qty1.mul(qty2).into_value(),
+        Symbols::Divide => {
+            if qty2.value.is_zero() {
+                return Err(math_error("the divisor should not be ZERO")?);
+            }
+            qty1.div(qty2).into_value()
+        }
+
+        Symbols::LessThan => Value::Boolean(qty1.compare(&qty2)? == Some(Ordering::Less)),
+        Symbols::MoreThan => Value::Boolean(qty1.compare(&qty2)? == Some(Ordering::Greater)),
+        Symbols::LessThanEqual => Value::Boolean(matches!(
+            qty1.compare(&qty2)?,
+            Some(Ordering::Less | Ordering::Equal)
+        )),
+        Symbols::MoreThanEqual => Value::Boolean(matches!(
+            qty1.compare(&qty2)?,
+            Some(Ordering::Greater | Ordering::Equal)
+        )),
+        Symbols::CompareEqual => Value::Boolean(qty1.compare(&qty2)? == Some(Ordering::Equal)),
+        Symbols::NotEqual => Value::Boolean(qty1.compare(&qty2)? != Some(Ordering::Equal)),
+        _ => {
+            let msg = format!("unexpected symbol `{}` for operating", operator);
+            return Err(internal_error(InternalComponent::Computer, &msg)?);
+        }
+    };
+    Ok(result)
+}
+
+// the name of the overloading method for the operator,
+// and the reflected name when the right operand is the object.
+fn overloading_method_name(operator: Symbols) -> Option<(&'static str, &'static str)> {
+    let names = match operator {
+        Symbols::Plus => ("__add__", "__radd__"),
+        Symbols::Minus => ("__sub__", "__rsub__"),
+        Symbols::Multiply => ("__mul__", "__rmul__"),
+        Symbols::Divide => ("__div__", "__rdiv__"),
+        Symbols::Modulo => ("__mod__", "__rmod__"),
+        Symbols::Power => ("__pow__", "__rpow__"),
+
+        Symbols::CompareEqual => ("__eq__", "__eq__"),
+        Symbols::NotEqual => ("__ne__", "__ne__"),
+        Symbols::LessThan => ("__lt__", "__gt__"),
+        Symbols::MoreThan => ("__gt__", "__lt__"),
+        Symbols::LessThanEqual => ("__le__", "__ge__"),
+        Symbols::MoreThanEqual => ("__ge__", "__le__"),
+        _ => return None,
+    };
+    Some(names)
+}
+
+// the operating with user-defined objects,
+// dispatches the operator to the overloading method of the class,
+// example: `a + b` -> `a.__add__(b)` | `b.__radd__(a)`
+pub fn object_operate(
+    val1: Value,
+    val2: Value,
+    operator: Symbols,
+    scope: &mut Scope,
+) -> Result<Value, ()> {
+    fn find_method(val: &Value, method_name: &str) -> Option<Function> {
+        let Value::Object(obj) = val else {
+            return None;
+        };
+        let obj_ref = obj.as_ref().borrow();
+        obj_ref.prototype.find_method(method_name)
+    }
+
+    let Some((method_name, reflected_name)) = overloading_method_name(operator) else {
+        let msg = format!(
+            "operator `{}` can not be overloaded",
+            operator.source_text()
+        );
+        return Err(syntax_error(&msg)?);
+    };
+
+    let (method, self_value, other_value) = if let Some(method) = find_method(&val1, method_name) {
+        (method, val1, val2)
+    } else if let Some(method) = find_method(&val2, reflected_name) {
+        (method, val2, val1)
+    } else if let (Symbols::Plus, Value::String(_), _) | (Symbols::Plus, _, Value::String(_)) =
+        (operator, &val1, &val2)
+    {
+        // concatenates the string with the result of `to_string` method
+        let str1 = to_string_hook::resolve(&val1, scope)?.unwrap_or(val1.to_raw_string());
+        let str2 = to_string_hook::resolve(&val2, scope)?.unwrap_or(val2.to_raw_string());
+        return Ok(Value::from(str1 + &str2));
+    } else if operator == Symbols::NotEqual {
+        // `a != b` -> `!(a == b)`
+        let is_equal = object_operate(val1, val2, Symbols::CompareEqual, scope)?;
+        return Ok(Value::Boolean(!is_equal.get_bool()));
+    } else {
+        // the reflected method is expected when the object is the right operand
+        let expected_name = if let Value::Object(_) = val1 {
+            method_name
+        } else {
+            reflected_name
+        };
+        let msg = format!(
+            "operator `{}` is not overloaded, method `{}` is expected",
+            operator.source_text(),
+            expected_name
+        );
+        return Err(syntax_error(&msg)?);
+    };
+
+    let Function::UserDefined(user_defined_fn) = method else {
+        let msg = format!("method `{}` should be user-defined", method_name);
+        return Err(syntax_error(&msg)?);
+    };
+    user_defined_function::invoke_with_values(
+        &user_defined_fn,
+        vec![self_value, other_value],
+        scope,
+    )
+}
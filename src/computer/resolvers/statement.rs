@@ -1,19 +1,108 @@
 use std::io::stdout;
 
 use crate::computer::resolvers::{assignment, expression};
-use crate::public::compile_time::ast::types::StatementNode;
-use crate::public::error::syntax_error;
+use crate::public::compile_time::ast::ast_enum::ASTVec;
+use crate::public::compile_time::ast::types::{IfStatement, StatementNode};
+use crate::public::error::{syntax_error, type_error};
 use crate::public::run_time::scope::Scope;
-use crate::public::value::value::{Value, VoidSign};
+use crate::public::value::value::{Value, ValueType, VoidSign};
 use crate::utils::output::print_line;
 
-use super::sequence;
+use super::{sequence, to_string_hook};
+
+// the items to iterate in `for-in` statement
+fn iterable_items(iterable_value: Value) -> Result<Box<dyn Iterator<Item = Value>>, ()> {
+    let items: Vec<Value> = match iterable_value {
+        // the range is iterated without allocation
+        Value::Range(range) => return Ok(Box::new(range.iter().map(Value::Number))),
+
+        Value::Array(arr) => arr.as_ref().borrow().iter().cloned().collect(),
+        Value::String(str) => str
+            .as_ref()
+            .borrow()
+            .chars()
+            .map(|ch| Value::from(ch.to_string()))
+            .collect(),
+        // the property names of the object
+        Value::Object(obj) => obj
+            .as_ref()
+            .borrow()
+            .prototype
+            .property_names()
+            .into_iter()
+            .map(Value::from)
+            .collect(),
+        _ => {
+            return Err(type_error(
+                Some("for-in loop"),
+                vec![
+                    ValueType::Array,
+                    ValueType::String,
+                    ValueType::Range,
+                    ValueType::Object,
+                ],
+                iterable_value.get_type(),
+            )?)
+        }
+    };
+    Ok(Box::new(items.into_iter()))
+}
+
+// returns `Some(..)` when the loop should be stopped,
+// the `return` sign is passed to the outer function.
+fn loop_body_resolve(body: &ASTVec, scope: &mut Scope) -> Result<Option<Value>, ()> {
+    for sequence in body {
+        let sequence_result = sequence::resolve(sequence, scope)?;
+
+        if let Value::Void(sign) = &sequence_result {
+            match sign {
+                // encount `break` | `brk`
+                VoidSign::Break(_) => return Ok(Some(Value::Void(VoidSign::Empty))),
+                // encount `return`
+                VoidSign::Return(_) => return Ok(Some(sequence_result)),
+                // encount `continue` | `ctn`
+                VoidSign::Continue => break,
+                VoidSign::Empty => {}
+            }
+        }
+    }
+    Ok(None)
+}
+
+// returns the value of the last sequence in the selected branch
+pub fn condition_resolve(if_statement: &IfStatement, scope: &mut Scope) -> Result<Value, ()> {
+    let condition_value = expression::resolve(&if_statement.condition, scope)?;
+
+    let branch_body = if condition_value.get_bool() {
+        &if_statement.body
+    } else if let Some(else_body) = &if_statement.else_body {
+        else_body
+    } else {
+        return Ok(Value::Void(VoidSign::Empty));
+    };
+
+    let mut branch_value = Value::Void(VoidSign::Empty);
+    for sequence in branch_body {
+        branch_value = sequence::resolve(sequence, scope)?;
+
+        // pass `brk`, `ctn` and `return` to the outer loop or function
+        if let Value::Void(VoidSign::Break(_) | VoidSign::Continue | VoidSign::Return(_)) =
+            branch_value
+        {
+            break;
+        }
+    }
+    Ok(branch_value)
+}
 
 pub fn resolve(statement_node: &StatementNode, scope: &mut Scope) -> Result<Value, ()> {
     let result = match statement_node {
         StatementNode::Output(expression_node) => {
             let output_value = expression::resolve(expression_node, scope)?;
-            print_line(&mut stdout(), output_value);
+            match to_string_hook::resolve(&output_value, scope)? {
+                Some(output_str) => print_line(&mut stdout(), output_str),
+                None => print_line(&mut stdout(), output_value),
+            }
             Value::Void(VoidSign::Empty)
         }
         StatementNode::ForLoop(for_statement) => {
@@ -33,8 +122,9 @@ pub fn resolve(statement_node: &StatementNode, scope: &mut Scope) -> Result<Valu
                 _ => return Err(syntax_error("invalid loop count for 'for' statement")?),
             }
 
+            let mut loop_result = Value::Void(VoidSign::Empty);
             let mut count = 0;
-            'outer: loop {
+            loop {
                 // these is used to control loop times
                 if !is_inf_loop {
                     if count == loop_count {
@@ -45,39 +135,55 @@ pub fn resolve(statement_node: &StatementNode, scope: &mut Scope) -> Result<Valu
 
                 // --- --- --- --- --- ---
 
-                'inner: for sequence in &for_statement.body {
-                    let sequence_result = sequence::resolve(sequence, scope)?;
-
-                    if let Value::Void(sign) = sequence_result {
-                        if let VoidSign::Break(_) = sign {
-                            // encount `break` | `brk`
-                            break 'outer;
-                        }
-                        if sign == VoidSign::Continue {
-                            // encount `continue` | `ctn`
-                            break 'inner;
-                        }
-                    }
+                if let Some(exit_value) = loop_body_resolve(&for_statement.body, scope)? {
+                    loop_result = exit_value;
+                    break;
                 }
             }
 
-            Value::Void(VoidSign::Empty)
+            loop_result
         }
-        StatementNode::Condition(if_statement) => {
-            let condition_value = expression::resolve(&if_statement.condition, scope)?;
+        StatementNode::ForInLoop(for_in_statement) => {
+            let iterable_value = expression::resolve(&for_in_statement.iterable, scope)?;
+            let items = iterable_items(iterable_value)?;
+            let mut loop_result = Value::Void(VoidSign::Empty);
 
-            if condition_value.get_bool() {
-                for sequence in &if_statement.body {
-                    let sequence_result = sequence::resolve(sequence, scope)?;
+            for (index, item) in items.enumerate() {
+                if let Some(index_name) = &for_in_statement.index_name {
+                    scope.assign(index_name.clone(), Value::from(index as i64));
+                }
+                scope.assign(for_in_statement.item_name.clone(), item);
+
+                if let Some(exit_value) = loop_body_resolve(&for_in_statement.body, scope)? {
+                    loop_result = exit_value;
+                    break;
+                }
+            }
 
-                    if let Value::Void(_) = sequence_result {
-                        return Ok(sequence_result);
+            loop_result
+        }
+        StatementNode::WhileLoop(while_statement) => {
+            let mut loop_result = Value::Void(VoidSign::Empty);
+            let mut is_first_loop = while_statement.is_do_while;
+            loop {
+                // the body of `do-while` runs once before checking
+                if !is_first_loop {
+                    let condition_value = expression::resolve(&while_statement.condition, scope)?;
+                    if !condition_value.get_bool() {
+                        break;
                     }
                 }
+                is_first_loop = false;
+
+                if let Some(exit_value) = loop_body_resolve(&while_statement.body, scope)? {
+                    loop_result = exit_value;
+                    break;
+                }
             }
 
-            Value::Void(VoidSign::Empty)
+            loop_result
         }
+        StatementNode::Condition(if_statement) => condition_resolve(if_statement, scope)?,
         StatementNode::Import(import_node) => {
             // import_node.type__ must be `ModuleType::BuildIn`
             scope.import_std(&import_node.target)?;
@@ -89,12 +195,13 @@ pub fn resolve(statement_node: &StatementNode, scope: &mut Scope) -> Result<Valu
 
         StatementNode::Continue => Value::Void(VoidSign::Continue),
         StatementNode::Break(expression_node) => {
+            // the bare `brk` stops the loop as well
             let expression_value = expression::resolve(expression_node, scope)?;
-            if expression_value == Value::Void(VoidSign::Empty) {
-                Value::Void(VoidSign::Empty)
-            } else {
-                Value::Void(VoidSign::Break(expression_value.into()))
-            }
+            Value::Void(VoidSign::Break(expression_value.into()))
+        }
+        StatementNode::Return(expression_node) => {
+            let expression_value = expression::resolve(expression_node, scope)?;
+            Value::Void(VoidSign::Return(expression_value.into()))
         }
     };
     return Ok(result);
@@ -0,0 +1,34 @@
+use crate::public::error::type_error;
+use crate::public::run_time::scope::Scope;
+use crate::public::value::function::Function;
+use crate::public::value::value::{Value, ValueType};
+
+use super::invocation::user_defined_function;
+
+const HOOK_METHOD_NAME: &str = "to_string";
+
+// returns the result of the `to_string` method
+// when the value is an object whose class defines it.
+pub fn resolve(value: &Value, scope: &mut Scope) -> Result<Option<String>, ()> {
+    let Value::Object(obj) = value else {
+        return Ok(None);
+    };
+    let method = obj
+        .as_ref()
+        .borrow()
+        .prototype
+        .find_method(HOOK_METHOD_NAME);
+    let Some(Function::UserDefined(method)) = method else {
+        return Ok(None);
+    };
+
+    let result = user_defined_function::invoke_with_values(&method, vec![value.clone()], scope)?;
+    match result {
+        Value::String(str) => Ok(Some(str.as_ref().borrow().clone())),
+        _ => Err(type_error(
+            Some("method `to_string`"),
+            vec![ValueType::String],
+            result.get_type(),
+        )?),
+    }
+}
@@ -6,20 +6,35 @@ pub enum CommandArg {
     Help,
     Timer,
     Headfile,
+    Division,
+    Precision,
+    Radix,
+    BitWidth,
+    Format,
 }
 
-pub const COMMAND_COUNT: usize = 4;
+pub const COMMAND_COUNT: usize = 9;
 pub const COMMANDS: [[&'static str; 2]; COMMAND_COUNT] = [
     ["-v", "--version"],
     ["-h", "--help"],
     ["-t", "--timer"],
     ["-hf", "--headfile"],
+    ["-d", "--division"],
+    ["-p", "--precision"],
+    ["-r", "--radix"],
+    ["-w", "--bit-width"],
+    ["-f", "--format"],
 ];
 pub const COMMAND_DESCRIPTIONS: [&'static str; COMMAND_COUNT] = [
     "print current executable file version and exit.",
     "print this help message.",
     "print extra execute duration message code execution.",
     "directly import variables in head files, must with script paths following.",
+    "set the result type of integer division, must with `fraction`, `float` or `int` following.",
+    "set the significant digits of decimal numbers, must with a number following, `0` for native float.",
+    "set the radix of integer output, must with `hex`, `oct`, `bin`, `dec` or a number between 2 and 36 following.",
+    "display integer output in two's-complement, must with the bit width following, `0` to disable, at most 128.",
+    "set the format of number output, must with a format spec like `.2f`, `,.3s`, `.4e` or `.2en` following.",
 ];
 
 impl CommandArg {
@@ -33,6 +48,16 @@ impl CommandArg {
             (COMMANDS[2][1], CommandArg::Timer),
             (COMMANDS[3][0], CommandArg::Headfile),
             (COMMANDS[3][1], CommandArg::Headfile),
+            (COMMANDS[4][0], CommandArg::Division),
+            (COMMANDS[4][1], CommandArg::Division),
+            (COMMANDS[5][0], CommandArg::Precision),
+            (COMMANDS[5][1], CommandArg::Precision),
+            (COMMANDS[6][0], CommandArg::Radix),
+            (COMMANDS[6][1], CommandArg::Radix),
+            (COMMANDS[7][0], CommandArg::BitWidth),
+            (COMMANDS[7][1], CommandArg::BitWidth),
+            (COMMANDS[8][0], CommandArg::Format),
+            (COMMANDS[8][1], CommandArg::Format),
         ])
     }
 }
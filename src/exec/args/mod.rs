@@ -4,8 +4,9 @@ mod help_msg;
 use std::{collections::VecDeque, io, process};
 
 use crate::public::{
-    env::{Env, ENV_OPTION},
-    run_time::scope::Scope,
+    env::{radix_from_name, DivisionMode, Env, ENV_OPTION, MAX_BIT_WIDTH, MAX_PRECISION},
+    run_time::{build_in, scope::Scope},
+    value::number_format::NumberFormat,
 };
 
 use super::{headfile, repl::repl, script};
@@ -42,6 +43,50 @@ fn args_resolve(
                     calc_env.version_output();
                     process::exit(0);
                 }
+                CommandArg::Division => {
+                    let mode_name = args.pop_front().unwrap_or_default();
+                    let Some(mode) = DivisionMode::from_name(&mode_name) else {
+                        println!("Invalid division mode: {}.", mode_name);
+                        process::exit(0);
+                    };
+                    unsafe { ENV_OPTION.division_mode = mode }
+                }
+                CommandArg::Precision => {
+                    let digits = args.pop_front().unwrap_or_default();
+                    let precision = digits.parse::<u64>().ok();
+                    let Some(precision) = precision.filter(|n| *n <= MAX_PRECISION) else {
+                        println!("Invalid precision: {}.", digits);
+                        process::exit(0);
+                    };
+                    unsafe { ENV_OPTION.precision = precision }
+                    // recompute the constants with the new precision
+                    scope.global.variables.extend(build_in::math_constants());
+                }
+                CommandArg::Radix => {
+                    let radix_name = args.pop_front().unwrap_or_default();
+                    let Some(radix) = radix_from_name(&radix_name) else {
+                        println!("Invalid radix: {}.", radix_name);
+                        process::exit(0);
+                    };
+                    unsafe { ENV_OPTION.output_radix = radix }
+                }
+                CommandArg::BitWidth => {
+                    let width = args.pop_front().unwrap_or_default();
+                    let bit_width = width.parse::<u32>().ok();
+                    let Some(bit_width) = bit_width.filter(|n| *n <= MAX_BIT_WIDTH) else {
+                        println!("Invalid bit width: {}.", width);
+                        process::exit(0);
+                    };
+                    unsafe { ENV_OPTION.bit_width = bit_width }
+                }
+                CommandArg::Format => {
+                    let spec = args.pop_front().unwrap_or_default();
+                    let Some(number_format) = NumberFormat::parse(&spec) else {
+                        println!("Invalid format spec: {}.", spec);
+                        process::exit(0);
+                    };
+                    unsafe { ENV_OPTION.number_format = number_format }
+                }
                 CommandArg::Headfile => {
                     // remaining args as headfile
                     calc_env.headfiles = args.clone();
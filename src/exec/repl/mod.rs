@@ -6,10 +6,12 @@ use std::time::Instant;
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
 
 use super::attempt::attempt;
+use super::script::RETURN_OUTSIDE_FUNCTION_MSG;
+use crate::computer::resolvers::to_string_hook;
 use crate::public::env::{Env, ENV_OPTION};
 use crate::public::error::{import_error, syntax_error};
 use crate::public::run_time::scope::Scope;
-use crate::public::value::value::Value;
+use crate::public::value::value::{Value, VoidSign};
 use crate::utils::completer::Completer;
 use crate::utils::line_editor::{LineEditor, Signal};
 use crate::utils::output::print_line;
@@ -23,6 +25,7 @@ fn import_all(scope: &mut Scope) -> Result<(), ()> {
     scope.import_std("Array")?;
     scope.import_std("FS")?;
     scope.import_std("BitOps")?;
+    scope.import_std("Sym")?;
     Ok(())
 }
 
@@ -80,14 +83,23 @@ pub fn repl(scope: &mut Scope, calc_env: Env) -> io::Result<()> {
         }
 
         if let Ok(val) = result {
-            if let Value::Void(_) = val {
+            if let Value::Void(VoidSign::Return(_)) = val {
+                syntax_error(RETURN_OUTSIDE_FUNCTION_MSG).unwrap_err();
+            } else if let Value::Void(_) = val {
                 continue;
             } else if let Value::String(_) = val {
                 print!("= ");
                 print_line(&mut stdout, val.str_format());
             } else {
+                // the object is printed with its `to_string` method
+                let Ok(obj_str) = to_string_hook::resolve(&val, scope) else {
+                    continue;
+                };
                 print!("= ");
-                print_line(&mut stdout, val);
+                match obj_str {
+                    Some(obj_str) => print_line(&mut stdout, obj_str),
+                    None => print_line(&mut stdout, val),
+                }
             }
         }
     }
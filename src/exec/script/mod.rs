@@ -4,10 +4,14 @@ pub mod readlines;
 use std::time::Instant;
 
 use crate::public::env::{Env, ENV_OPTION};
+use crate::public::error::syntax_error;
 use crate::public::run_time::scope::Scope;
+use crate::public::value::value::{Value, VoidSign};
 
 use super::attempt::attempt;
 
+pub const RETURN_OUTSIDE_FUNCTION_MSG: &str = "`return` outside of function body";
+
 pub fn env_resolve(calc_env: Env, scope: &mut Scope) {
     let script_path = calc_env.script_path.unwrap();
 
@@ -23,38 +27,84 @@ pub fn env_resolve(calc_env: Env, scope: &mut Scope) {
     }
 }
 
+// checks if the next non-empty line continues
+// the if-statement with `else` or `elif`.
+fn is_else_following(lines: &[String]) -> bool {
+    let Some(next_line) = lines.iter().find(|line| !line.is_empty()) else {
+        return false;
+    };
+    let first_word = next_line
+        .split(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+        .next()
+        .unwrap_or_default();
+    first_word == "else" || first_word == "elif"
+}
+
+// the count of opening braces minus closing braces,
+// the braces in string literals are skipped.
+fn brace_delta(line: &str) -> i32 {
+    let mut delta = 0;
+    let mut is_in_string = false;
+    let mut is_escape_char = false;
+
+    for ch in line.chars() {
+        if is_in_string {
+            if is_escape_char {
+                is_escape_char = false;
+            } else if ch == '\\' {
+                is_escape_char = true;
+            } else if ch == '\'' || ch == '\"' {
+                is_in_string = false;
+            }
+            continue;
+        }
+        match ch {
+            '\'' | '\"' => is_in_string = true,
+            '{' => delta += 1,
+            '}' => delta -= 1,
+            _ => {}
+        }
+    }
+    delta
+}
+
 pub fn run(path: &str, scope: &mut Scope) {
-    let Ok(mut script_lines) = readlines::resolve(path) else {
+    let Ok(script_lines) = readlines::resolve(path) else {
         println!("Invalid script file.");
         return;
     };
+    let script_lines = script_lines
+        .map_while(Result::ok)
+        .map(pre_processer::process)
+        .collect::<Vec<String>>();
 
     let mut cached_multiline = String::new();
-    let mut line_count = 0;
     let mut brace_count = 0;
 
-    while let Some(Ok(current_line)) = script_lines.next() {
-        let mut current_line = pre_processer::process(current_line);
-        line_count += 1;
+    for (index, current_line) in script_lines.iter().enumerate() {
+        let mut current_line = current_line.clone();
+        let line_count = index + 1;
 
         // skip blank line
         if current_line.is_empty() {
             continue;
         }
 
-        if current_line.ends_with('{') {
-            brace_count += 1;
-        }
-        if current_line.ends_with('}') {
-            brace_count -= 1;
-            if brace_count > 0 {
-                // nested function or class
-                current_line.push(';');
-            }
+        let else_following =
+            current_line.ends_with('}') && is_else_following(&script_lines[line_count..]);
+
+        brace_count += brace_delta(&current_line);
+
+        // the line like `} while cond` or `if a { ... }`
+        let is_closing_line = current_line.ends_with('}')
+            || (current_line.starts_with('}') && !current_line.ends_with('{'));
+        if is_closing_line && brace_count > 0 && !else_following {
+            // the block ends inside the outer block
+            current_line.push(';');
         }
 
         match brace_count {
-            x if x <= 0 => {
+            x if x <= 0 && !else_following => {
                 let line_to_exec = if cached_multiline.is_empty() {
                     &current_line
                 } else {
@@ -62,7 +112,12 @@ pub fn run(path: &str, scope: &mut Scope) {
                     &cached_multiline
                 };
                 // execuse the line
-                let line_result = attempt(line_to_exec, scope);
+                let line_result = match attempt(line_to_exec, scope) {
+                    Ok(Value::Void(VoidSign::Return(_))) => {
+                        syntax_error(RETURN_OUTSIDE_FUNCTION_MSG)
+                    }
+                    result => result.map(|_| ()),
+                };
                 cached_multiline.clear();
 
                 if line_result.is_err() {
@@ -72,8 +127,7 @@ pub fn run(path: &str, scope: &mut Scope) {
                     break;
                 }
             }
-            x if x > 0 => cached_multiline.extend(current_line.chars()),
-            _ => unreachable!(),
+            _ => cached_multiline.extend(current_line.chars()),
         }
     }
 }
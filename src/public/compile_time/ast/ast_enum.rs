@@ -1,11 +1,11 @@
 use core::fmt;
 
-use crate::public::value::{number::Number, symbols::Symbols};
+use crate::public::value::{number::Number, quantity::Quantity, symbols::Symbols};
 
 use super::types::{
     ArrayElementReadingNode, ArrayLiteralNode, AssignmentNode, ClassDefinitionNode, ExpressionNode,
-    FunctionDefinitionNode, ImportNode, InstantiationNode, InvocationNode, LazyExpressionNode,
-    ObjectReadingNode, StatementNode, VariableNode,
+    FunctionDefinitionNode, IfStatement, ImportNode, InstantiationNode, InvocationNode,
+    LazyExpressionNode, ObjectReadingNode, StatementNode, VariableNode,
 };
 
 pub struct RootNode {
@@ -17,6 +17,7 @@ pub enum ASTNode {
     Comment,
 
     NumberLiteral(Number),
+    QuantityLiteral(Box<Quantity>),
     StringLiteral(String),
     SymbolLiteral(Symbols),
 
@@ -26,6 +27,7 @@ pub enum ASTNode {
     ArrayElementReading(Box<ArrayElementReadingNode>),
     Expression(Box<ExpressionNode>),
     LazyExpression(Box<LazyExpressionNode>),
+    ConditionalExpression(Box<IfStatement>),
 
     Invocation(Box<InvocationNode>),
     Statement(Box<StatementNode>),
@@ -44,6 +46,7 @@ impl fmt::Display for ASTNode {
         let content = match self {
             ASTNode::Comment => "Comment",
             ASTNode::NumberLiteral(_) => "NumberLiteral",
+            ASTNode::QuantityLiteral(_) => "QuantityLiteral",
             ASTNode::StringLiteral(_) => "StringLiteral",
             ASTNode::SymbolLiteral(_) => "SymbolLiteral",
             ASTNode::Variable(_) => "Variable",
@@ -52,6 +55,7 @@ impl fmt::Display for ASTNode {
             ASTNode::ArrayElementReading(_) => "ArrayElementReading",
             ASTNode::Expression(_) => "Expression",
             ASTNode::LazyExpression(_) => "LazyExpression",
+            ASTNode::ConditionalExpression(_) => "ConditionalExpression",
             ASTNode::Invocation(_) => "Invocation",
             ASTNode::Statement(_) => "Statement",
             ASTNode::ImportStatement(_) => "ImportStatement",
@@ -31,10 +31,14 @@ pub struct ExpressionNode {
 pub struct LazyExpressionNode {
     pub sub_sequence: ASTNode,
 }
+// the named arguments: `f(x = 1, y = 2)`
+pub type NamedParams = Vec<(String, ExpressionNode)>;
+
 #[derive(PartialEq, Clone)]
 pub struct InvocationNode {
     pub caller: ASTNode,
     pub params: Vec<ExpressionNode>,
+    pub named_params: NamedParams,
 }
 #[derive(PartialEq, Clone)]
 pub struct ObjectReadingNode {
@@ -50,6 +54,8 @@ pub struct FunctionDefinitionNode {
 }
 #[derive(PartialEq, Clone)]
 pub struct ClassDefinitionNode {
+    // the name of the parent class: `cl Parent { ... }`
+    pub parent: Option<String>,
     pub properties: Vec<Property>,
     pub method_nodes: Vec<Rc<FunctionDefinitionNode>>,
 }
@@ -57,6 +63,7 @@ pub struct ClassDefinitionNode {
 pub struct InstantiationNode {
     pub class: String,
     pub params: ArrayLiteralNode,
+    pub named_params: NamedParams,
 }
 
 // --- --- --- ---|
@@ -66,12 +73,15 @@ pub struct InstantiationNode {
 pub enum StatementNode {
     Output(ExpressionNode),
     ForLoop(ForStatement),
+    ForInLoop(ForInStatement),
+    WhileLoop(WhileStatement),
     Condition(IfStatement),
     Import(ImportNode),
     GlobalAssignment(AssignmentNode),
 
     Continue,
     Break(ExpressionNode),
+    Return(ExpressionNode),
 }
 #[derive(PartialEq, Clone)]
 pub struct ForStatement {
@@ -79,9 +89,28 @@ pub struct ForStatement {
     pub body: ASTVec,
 }
 #[derive(PartialEq, Clone)]
+pub struct ForInStatement {
+    // `for index, item in iterable { ... }`
+    pub index_name: Option<String>,
+    pub item_name: String,
+    pub iterable: ExpressionNode,
+    pub body: ASTVec,
+}
+#[derive(PartialEq, Clone)]
+pub struct WhileStatement {
+    pub condition: ExpressionNode,
+    pub body: ASTVec,
+    // `do { ... } while cond`,
+    // checks the condition after each iteration.
+    pub is_do_while: bool,
+}
+#[derive(PartialEq, Clone)]
 pub struct IfStatement {
     pub condition: ExpressionNode,
     pub body: ASTVec,
+    // the `else` branch,
+    // `elif` is stored as a nested if-statement.
+    pub else_body: Option<ASTVec>,
 }
 
 #[derive(PartialEq, Clone)]
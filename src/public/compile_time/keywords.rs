@@ -5,10 +5,16 @@ pub enum Keyword {
     Out,
 
     For,
+    In,
+    While,
+    Do,
     If,
+    Else,
+    Elif,
 
     Continue,
     Break,
+    Return,
 
     Import,
 
@@ -37,12 +43,18 @@ impl Keyword {
     }
 }
 
-pub const KEYWORD_PAIRS: [(&'static str, Keyword); 10] = [
+pub const KEYWORD_PAIRS: [(&'static str, Keyword); 16] = [
     ("out", Keyword::Out),
     ("for", Keyword::For),
+    ("in", Keyword::In),
+    ("while", Keyword::While),
+    ("do", Keyword::Do),
     ("if", Keyword::If),
+    ("else", Keyword::Else),
+    ("elif", Keyword::Elif),
     ("ctn", Keyword::Continue),
     ("brk", Keyword::Break),
+    ("return", Keyword::Return),
     ("import", Keyword::Import),
     ("fn", Keyword::Function),
     ("cl", Keyword::Class),
@@ -55,9 +67,15 @@ impl fmt::Display for Keyword {
         match self {
             Keyword::Out => write!(f, "out"),
             Keyword::For => write!(f, "for"),
+            Keyword::In => write!(f, "in"),
+            Keyword::While => write!(f, "while"),
+            Keyword::Do => write!(f, "do"),
             Keyword::If => write!(f, "if"),
+            Keyword::Else => write!(f, "else"),
+            Keyword::Elif => write!(f, "elif"),
             Keyword::Continue => write!(f, "continue"),
             Keyword::Break => write!(f, "break"),
+            Keyword::Return => write!(f, "return"),
             Keyword::Import => write!(f, "import"),
             Keyword::Function => write!(f, "function"),
             Keyword::Class => write!(f, "class"),
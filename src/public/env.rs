@@ -1,15 +1,73 @@
 use std::collections::VecDeque;
 
+use crate::public::value::number_format::{NumberFormat, DEFAULT_FORMAT};
+
+// the result type of `Int / Int`
+#[derive(PartialEq, Clone, Copy)]
+pub enum DivisionMode {
+    Fraction, // exact fraction, e.g. 1 / 2 -> (1 / 2)
+    Float,    // float number, e.g. 1 / 2 -> 0.5
+    Int,      // truncated integer, e.g. 1 / 2 -> 0
+}
+
+impl DivisionMode {
+    pub fn from_name(name: &str) -> Option<DivisionMode> {
+        match name {
+            "fraction" => Some(DivisionMode::Fraction),
+            "float" => Some(DivisionMode::Float),
+            "int" => Some(DivisionMode::Int),
+            _ => None,
+        }
+    }
+}
+
+// the radix of integer output, accepts `hex`, `oct`, `bin`, `dec`
+// or a number between 2 and 36.
+pub fn radix_from_name(name: &str) -> Option<u32> {
+    match name {
+        "hex" => Some(16),
+        "oct" => Some(8),
+        "bin" => Some(2),
+        "dec" => Some(10),
+        _ => match name.parse::<u32>() {
+            Ok(radix) if (2..=36).contains(&radix) => Some(radix),
+            _ => None,
+        },
+    }
+}
+
+// every decimal operation allocates `precision` digits
+pub const MAX_PRECISION: u64 = 1000;
+// the two's-complement output allocates `2^bit_width`,
+// so the width is bounded.
+pub const MAX_BIT_WIDTH: u32 = 128;
+
 pub struct EnvOption {
     pub timer: bool,
     pub is_repl: bool,
     pub support_ansi: bool,
+    pub division_mode: DivisionMode,
+    // significant digits of decimal numbers,
+    // 0 for the native float numbers.
+    pub precision: u64,
+    // the programmer output mode,
+    // integers are displayed in `output_radix`,
+    // and in two's-complement when `bit_width` is not 0.
+    pub output_radix: u32,
+    pub bit_width: u32,
+    // the format of number output, set with `out_format(spec)`
+    pub number_format: NumberFormat,
 }
 
 pub static mut ENV_OPTION: EnvOption = EnvOption {
     timer: false,
     is_repl: false,
     support_ansi: false,
+    division_mode: DivisionMode::Fraction,
+    precision: 0,
+    output_radix: 10,
+    bit_width: 0,
+    number_format: DEFAULT_FORMAT,
 };
 
 // --- --- --- --- --- ---
@@ -13,7 +13,7 @@ fn error_name_output(name: &str) -> StyledContent<&str> {
     name.white().on_red().bold()
 }
 
-const TYPE_ERROR_NAME: &'static str = " TypeError ";
+const TYPE_ERROR_NAME: &str = " TypeError ";
 pub fn type_error(param: Option<&str>, expected: Vec<ValueType>, found: ValueType) -> ErrorResult {
     // Vec<ValueType> -> "{type}/{type} ..."
     fn join(mut type_vec: Vec<ValueType>) -> String {
@@ -39,14 +39,14 @@ pub fn type_error(param: Option<&str>, expected: Vec<ValueType>, found: ValueTyp
     Err(())
 }
 
-const RANGE_ERROR_NAME: &'static str = " RangeError ";
-pub fn range_error<T: Display>(param: &str, expected: T, found: usize) -> ErrorResult {
+const RANGE_ERROR_NAME: &str = " RangeError ";
+pub fn range_error<T: Display, U: Display>(param: &str, expected: T, found: U) -> ErrorResult {
     print!("{} for \"{}\"", error_name_output(RANGE_ERROR_NAME), param);
     print_line__(format!(": expected {}, found {}.", expected, found));
     Err(())
 }
 
-const SYNTAX_ERROR_NAME: &'static str = " SyntaxError ";
+const SYNTAX_ERROR_NAME: &str = " SyntaxError ";
 pub fn syntax_error(msg: &str) -> ErrorResult {
     print_line__(format!(
         "{}: {}.\r",
@@ -56,7 +56,7 @@ pub fn syntax_error(msg: &str) -> ErrorResult {
     Err(())
 }
 
-const ASSIGNMENT_ERROR_NAME: &'static str = " SyntaxError ";
+const ASSIGNMENT_ERROR_NAME: &str = " SyntaxError ";
 pub fn assignment_error(msg: &str) -> ErrorResult {
     print_line__(format!(
         "{}: {}.",
@@ -66,7 +66,7 @@ pub fn assignment_error(msg: &str) -> ErrorResult {
     Err(())
 }
 
-const REFERENCE_ERROR_NAME: &'static str = " ReferenceError ";
+const REFERENCE_ERROR_NAME: &str = " ReferenceError ";
 pub enum ReferenceType {
     Variable,
     Property,
@@ -84,7 +84,7 @@ pub fn reference_error(type__: ReferenceType, target_name: &str) -> ErrorResult
     Err(())
 }
 
-const IMPORT_ERROR_NAME: &'static str = " ImportError ";
+const IMPORT_ERROR_NAME: &str = " ImportError ";
 pub fn import_error(msg: &str) -> ErrorResult {
     print_line__(format!(
         "{}: {}.",
@@ -94,6 +94,12 @@ pub fn import_error(msg: &str) -> ErrorResult {
     Err(())
 }
 
+const MATH_ERROR_NAME: &str = " MathError ";
+pub fn math_error(msg: &str) -> ErrorResult {
+    print_line__(format!("{}: {}.", error_name_output(MATH_ERROR_NAME), msg));
+    Err(())
+}
+
 // --- --- --- --- --- ---
 
 pub enum InternalComponent {
@@ -117,7 +123,7 @@ impl fmt::Display for InternalComponent {
     }
 }
 
-const INTERNAL_ERROR_NAME: &'static str = " InternalError ";
+const INTERNAL_ERROR_NAME: &str = " InternalError ";
 pub fn internal_error(from: InternalComponent, msg: &str) -> ErrorResult {
     print_line__(format!(
         "{} from {}: {}.",
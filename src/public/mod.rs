@@ -7,9 +7,24 @@ pub mod env;
 pub mod error;
 pub mod value;
 
+use compile_time::ast::types::ExpressionNode;
 use value::value::ValueType;
 
 pub trait Param {
     fn type__(&self) -> ValueType;
     fn identi(&self) -> &str;
+
+    // the optional param can be omitted in invocation
+    fn is_optional(&self) -> bool {
+        false
+    }
+    // the default value of the omitted optional param,
+    // `Void` is used if there is no default value.
+    fn default_value(&self) -> Option<&ExpressionNode> {
+        None
+    }
+    // the rest param collects the extra arguments into an array
+    fn is_rest(&self) -> bool {
+        false
+    }
 }
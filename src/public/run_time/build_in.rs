@@ -8,7 +8,10 @@ use crate::public::std::modules::bit_ops::BitOpsFn;
 use crate::public::std::modules::file_system::FileSysFn;
 use crate::public::std::modules::math::MathFn;
 use crate::public::std::modules::string::StringFn;
+use crate::public::std::modules::sym::SymFn;
 
+use crate::public::value::decimal;
+use crate::public::value::number::Number;
 use crate::public::value::value::{Value, ValueType};
 
 #[derive(PartialEq, Clone)]
@@ -19,6 +22,7 @@ pub enum BuildInFnIdenti {
     String(StringFn),
     FileSystem(FileSysFn),
     BitOps(BitOpsFn),
+    Sym(SymFn),
 }
 
 pub fn constants() -> HashMap<String, Value> {
@@ -50,9 +54,31 @@ pub fn constants() -> HashMap<String, Value> {
             String::from("OBJECT"),
             Value::from(ValueType::Object as i64),
         ),
-        (String::from("PI"), Value::from(PI)),
-        (String::from("E"), Value::from(E)),
+        (
+            String::from("QUANTITY"),
+            Value::from(ValueType::Quantity as i64),
+        ),
+        (String::from("RANGE"), Value::from(ValueType::Range as i64)),
         (String::from("true"), Value::Boolean(true)),
         (String::from("false"), Value::Boolean(false)),
     ])
+    .into_iter()
+    .chain(math_constants())
+    .collect()
+}
+
+// the math constants depend on the current precision
+pub fn math_constants() -> [(String, Value); 2] {
+    let (pi, e) = if decimal::is_enabled() {
+        (
+            Number::Decimal(decimal::pi()),
+            Number::Decimal(decimal::e()),
+        )
+    } else {
+        (Number::Float(PI), Number::Float(E))
+    };
+    [
+        (String::from("PI"), Value::Number(pi)),
+        (String::from("E"), Value::Number(e)),
+    ]
 }
@@ -16,7 +16,7 @@ pub fn std_resolve(scope: &mut Scope, target_module: &StdModules, module_name: &
             scope.global.variables.extend(fn_list);
         }
 
-        StdModules::FileSystem | StdModules::Math => {
+        StdModules::FileSystem | StdModules::Math | StdModules::Sym => {
             let module_obj = target_module.get_obj_entry();
             scope.assign(String::from(module_name), Value::from(module_obj));
         }
@@ -1,9 +1,12 @@
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
 use crate::exec::script;
 use crate::public::error::{import_error, reference_error, ReferenceType};
 use crate::public::std::StdModules;
+use crate::public::value::function::Function;
+use crate::public::value::oop::class::Class;
 use crate::public::value::oop::module::module_create;
 use crate::public::value::value::VoidSign;
 use crate::utils::completer::Completer;
@@ -24,18 +27,202 @@ impl GlobalScope {
 
 pub struct LocalScope {
     pub variables: HashMap<String, Value>,
+    // the local scope where the function is defined,
+    // its variables are captured by reference.
+    // the function stored in the scope it captures makes a reference cycle,
+    // which is broken by `LocalScope::release` when the function returns
+    // if no function escapes from the scope.
+    pub parent: Option<Rc<RefCell<LocalScope>>>,
 }
 impl LocalScope {
     pub fn init() -> LocalScope {
         LocalScope {
             variables: HashMap::<String, Value>::new(),
+            parent: None,
         }
     }
+
+    // clears the finished function scope if it is only referred by
+    // the values stored in itself, like the recursive inner function
+    // or the closure kept in an array or object, to break the reference cycle.
+    pub fn release(scope: Rc<RefCell<LocalScope>>) {
+        if Rc::strong_count(&scope) == 1 {
+            // not captured by any function
+            return;
+        }
+
+        let mut graph = RefGraph::default();
+        let root = graph.visit_scope(RefGraph::NO_SOURCE, &scope);
+        if graph.is_only_self_referred(root) {
+            scope.borrow_mut().variables.clear();
+        }
+    }
+
+    fn read_var(&self, var_name: &str) -> Option<Value> {
+        if let Some(val) = self.variables.get(var_name) {
+            return Some(val.clone());
+        }
+        let parent = self.parent.as_ref()?;
+        parent.borrow().read_var(var_name)
+    }
+    // updates the variable in the nearest scope that contains it,
+    // returns the value back if the variable is not found.
+    fn update(&mut self, var_name: &str, value: Value) -> Option<Value> {
+        if let Some(target) = self.variables.get_mut(var_name) {
+            *target = value;
+            return None;
+        }
+        match &self.parent {
+            Some(parent) => parent.borrow_mut().update(var_name, value),
+            None => Some(value),
+        }
+    }
+}
+
+// the reference graph from a finished function scope,
+// where the nodes are the `Rc` pointers identified by their address.
+#[derive(Default)]
+struct RefGraph {
+    nodes: HashMap<usize, RefNode>,
+    // some value is being mutated and can not be visited
+    is_incomplete: bool,
+}
+struct RefNode {
+    strong_count: usize,
+    // the count of references from the other nodes in the graph
+    referred_count: usize,
+    referrers: Vec<usize>,
+}
+
+impl RefGraph {
+    const NO_SOURCE: usize = 0;
+
+    // records the reference from `source` to `target`,
+    // returns the address of `target` and whether it is newly visited.
+    fn add_ref<T: ?Sized>(&mut self, source: usize, target: &Rc<T>) -> (usize, bool) {
+        let address = Rc::as_ptr(target) as *const () as usize;
+        let is_new = !self.nodes.contains_key(&address);
+        let node = self.nodes.entry(address).or_insert(RefNode {
+            strong_count: Rc::strong_count(target),
+            referred_count: 0,
+            referrers: vec![],
+        });
+        if source != RefGraph::NO_SOURCE {
+            node.referred_count += 1;
+            node.referrers.push(source);
+        }
+        (address, is_new)
+    }
+
+    fn visit_scope(&mut self, source: usize, scope: &Rc<RefCell<LocalScope>>) -> usize {
+        let (address, is_new) = self.add_ref(source, scope);
+        if is_new {
+            let Ok(scope_ref) = scope.try_borrow() else {
+                self.is_incomplete = true;
+                return address;
+            };
+            for value in scope_ref.variables.values() {
+                self.visit_value(address, value);
+            }
+            if let Some(parent) = &scope_ref.parent {
+                self.visit_scope(address, parent);
+            }
+        }
+        address
+    }
+    fn visit_function(&mut self, source: usize, function: &Function) {
+        let Function::UserDefined(function) = function else {
+            return;
+        };
+        let (address, is_new) = self.add_ref(source, function);
+        if let (true, Some(closure)) = (is_new, &function.closure) {
+            self.visit_scope(address, closure);
+        }
+    }
+    fn visit_class(&mut self, source: usize, class: &Rc<Class>) {
+        let (address, is_new) = self.add_ref(source, class);
+        if !is_new {
+            return;
+        }
+        for method in class.own_methods() {
+            self.visit_function(address, method);
+        }
+        if let Some(parent) = &class.parent {
+            self.visit_class(address, parent);
+        }
+    }
+    fn visit_value(&mut self, source: usize, value: &Value) {
+        match value {
+            Value::Function(function) => self.visit_function(source, function),
+            Value::Class(class) => self.visit_class(source, class),
+            Value::Array(arr) => {
+                let (address, is_new) = self.add_ref(source, arr);
+                if !is_new {
+                    return;
+                }
+                let Ok(arr_ref) = arr.try_borrow() else {
+                    self.is_incomplete = true;
+                    return;
+                };
+                for element in arr_ref.iter() {
+                    self.visit_value(address, element);
+                }
+            }
+            Value::Object(obj) => {
+                let (address, is_new) = self.add_ref(source, obj);
+                if !is_new {
+                    return;
+                }
+                let Ok(obj_ref) = obj.try_borrow() else {
+                    self.is_incomplete = true;
+                    return;
+                };
+                self.visit_class(address, &obj_ref.prototype);
+                let data_list = obj_ref.data_list.iter().flatten().map(|(_, cell)| cell);
+                let data_map = obj_ref.data_map.iter().flat_map(|map| map.values());
+                for cell in data_list.chain(data_map) {
+                    let (cell_address, is_new) = self.add_ref(address, cell);
+                    if !is_new {
+                        continue;
+                    }
+                    match cell.try_borrow() {
+                        Ok(value) => self.visit_value(cell_address, &value),
+                        Err(_) => self.is_incomplete = true,
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // checks whether every node that refers to the `root`,
+    // directly or not, is only referred by the nodes in the graph,
+    // the `root` is also referred by the caller of `release`.
+    fn is_only_self_referred(&self, root: usize) -> bool {
+        if self.is_incomplete {
+            return false;
+        }
+        let mut checked = HashSet::from([root]);
+        let mut pending = vec![root];
+        while let Some(address) = pending.pop() {
+            let node = &self.nodes[&address];
+            let outer_count = (address == root) as usize;
+            if node.strong_count != node.referred_count + outer_count {
+                return false;
+            }
+            for referrer in &node.referrers {
+                if checked.insert(*referrer) {
+                    pending.push(*referrer);
+                }
+            }
+        }
+        true
+    }
 }
 
 // --- --- --- --- --- ---
 
-const STD_MODULE_COUNT: usize = 6;
+const STD_MODULE_COUNT: usize = 7;
 const STD_MODULE_DATA: [(&'static str, StdModules); STD_MODULE_COUNT] = [
     ("Basic", StdModules::Basic),
     ("Math", StdModules::Math),
@@ -43,10 +230,11 @@ const STD_MODULE_DATA: [(&'static str, StdModules); STD_MODULE_COUNT] = [
     ("String", StdModules::String),
     ("FS", StdModules::FileSystem),
     ("BitOps", StdModules::BitOps),
+    ("Sym", StdModules::Sym),
 ];
 pub struct Scope {
     pub global: GlobalScope,
-    pub local: Option<LocalScope>,
+    pub local: Option<Rc<RefCell<LocalScope>>>,
     pub completer: Option<Completer>,
     user_module_imported: HashSet<String>,
     std_module_imported: [bool; STD_MODULE_COUNT],
@@ -82,22 +270,26 @@ impl Scope {
         // the local-scope is preferred.
         match &mut self.local {
             Some(local_scope) => {
-                // usually in a function invocation.
-                local_scope.variables.insert(var_name, value)
+                // usually in a function invocation,
+                // the captured variable is assigned preferentially.
+                let mut local_ref = local_scope.borrow_mut();
+                if let Some(value) = local_ref.update(&var_name, value) {
+                    local_ref.variables.insert(var_name, value);
+                }
             }
             None => {
                 if let Some(completer) = &mut self.completer {
                     completer.insert(&var_name);
                 }
-                self.global.variables.insert(var_name, value)
+                self.global.variables.insert(var_name, value);
             }
         };
     }
     pub fn read_var(&self, var_name: &str) -> Result<Value, ()> {
         // use local-scope preferer
         if let Some(local_scope) = &self.local {
-            if let Some(val) = local_scope.variables.get(var_name) {
-                return Ok(val.clone());
+            if let Some(val) = local_scope.borrow().read_var(var_name) {
+                return Ok(val);
             }
         };
 
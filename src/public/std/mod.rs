@@ -1,4 +1,4 @@
-use self::modules::{basic, bit_ops, file_system, math};
+use self::modules::{basic, bit_ops, file_system, math, sym};
 use super::value::{
     oop::{class::Class, object::Object},
     value::Value,
@@ -17,6 +17,7 @@ pub enum StdModules {
     String,
     FileSystem,
     BitOps,
+    Sym,
 }
 
 impl StdModules {
@@ -32,6 +33,7 @@ impl StdModules {
         match self {
             StdModules::Math => math::module_object(),
             StdModules::FileSystem => file_system::module_object(),
+            StdModules::Sym => sym::module_object(),
             _ => unreachable!(),
         }
     }
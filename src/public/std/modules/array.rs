@@ -23,47 +23,47 @@ pub enum ArrayFn {
 pub fn module_class() -> Class {
     let push = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::Void, "element"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::Void, "element", false),
         ],
         identi: BuildInFnIdenti::Array(ArrayFn::PUSH),
     };
 
     let pop = BuildInFunction {
-        params: vec![BuildInFnParam(ValueType::Object, "self")],
+        params: vec![BuildInFnParam(ValueType::Object, "self", false)],
         identi: BuildInFnIdenti::Array(ArrayFn::POP),
     };
 
     let shift = BuildInFunction {
-        params: vec![BuildInFnParam(ValueType::Object, "self")],
+        params: vec![BuildInFnParam(ValueType::Object, "self", false)],
         identi: BuildInFnIdenti::Array(ArrayFn::SHIFT),
     };
     let unshift = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::Void, "element"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::Void, "element", false),
         ],
         identi: BuildInFnIdenti::Array(ArrayFn::UNSHIFT),
     };
     let insert = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::Number, "index"),
-            BuildInFnParam(ValueType::Void, "element"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::Number, "index", false),
+            BuildInFnParam(ValueType::Void, "element", false),
         ],
         identi: BuildInFnIdenti::Array(ArrayFn::INSERT),
     };
     let remove = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::Number, "index"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::Number, "index", false),
         ],
         identi: BuildInFnIdenti::Array(ArrayFn::REMOVE),
     };
     let join = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::String, "divider"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::String, "divider", false),
         ],
         identi: BuildInFnIdenti::Array(ArrayFn::JOIN),
     };
@@ -1,15 +1,24 @@
 use std::io::{self, Write};
 use std::process;
 
+use bigdecimal::BigDecimal;
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use num_bigint::BigInt;
 
-use crate::public::error::{internal_error, type_error, InternalComponent};
-use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::computer::resolvers::to_string_hook;
+use crate::public::env::{radix_from_name, DivisionMode, ENV_OPTION, MAX_BIT_WIDTH, MAX_PRECISION};
+use crate::public::error::{
+    internal_error, math_error, range_error, syntax_error, type_error, InternalComponent,
+};
+use crate::public::run_time::build_in::{self, BuildInFnIdenti};
 use crate::public::run_time::scope::Scope;
 use crate::public::std::utils::str_to_num::str_to_num;
 use crate::public::value::array::ArrayLiteral;
+use crate::public::value::decimal;
 use crate::public::value::function::{BuildInFnParam, BuildInFunction};
 use crate::public::value::number::Number;
+use crate::public::value::number_format::NumberFormat;
+use crate::public::value::quantity::{unit_parse, Quantity};
 use crate::public::value::value::{Value, ValueType, VoidSign};
 
 use super::super::utils::get_val::get_val;
@@ -30,27 +39,92 @@ pub enum BasicFn {
     ARRAY,
     ASCII,
     LEN,
+    INSTANCEOF,
 
+    DIVMODE,
+    PRECISION,
+    OUTRADIX,
+    BITWIDTH,
+    TORADIX,
+    FROMRADIX,
+    FORMAT,
+    OUTFORMAT,
+    TO,
     EXIT,
 }
 
 pub fn function_list() -> Vec<(String, Value)> {
     let input = BuildInFunction {
-        params: vec![BuildInFnParam(ValueType::String, "prompt")],
+        params: vec![BuildInFnParam(ValueType::String, "prompt", false)],
         identi: BuildInFnIdenti::Basic(BasicFn::INPUT),
     };
+    let div_mode = BuildInFunction {
+        params: vec![BuildInFnParam(ValueType::String, "mode", false)],
+        identi: BuildInFnIdenti::Basic(BasicFn::DIVMODE),
+    };
+    let precision = BuildInFunction {
+        params: vec![BuildInFnParam(ValueType::Number, "digits", false)],
+        identi: BuildInFnIdenti::Basic(BasicFn::PRECISION),
+    };
+    let out_radix = BuildInFunction {
+        params: vec![BuildInFnParam(ValueType::String, "radix", false)],
+        identi: BuildInFnIdenti::Basic(BasicFn::OUTRADIX),
+    };
+    let bit_width = BuildInFunction {
+        params: vec![BuildInFnParam(ValueType::Number, "bits", false)],
+        identi: BuildInFnIdenti::Basic(BasicFn::BITWIDTH),
+    };
+    let to_radix = BuildInFunction {
+        params: vec![
+            BuildInFnParam(ValueType::Number, "input", false),
+            BuildInFnParam(ValueType::Number, "radix", false),
+        ],
+        identi: BuildInFnIdenti::Basic(BasicFn::TORADIX),
+    };
+    let from_radix = BuildInFunction {
+        params: vec![
+            BuildInFnParam(ValueType::String, "input", false),
+            BuildInFnParam(ValueType::Number, "radix", false),
+        ],
+        identi: BuildInFnIdenti::Basic(BasicFn::FROMRADIX),
+    };
+    let format = BuildInFunction {
+        params: vec![
+            BuildInFnParam(ValueType::Number, "input", false),
+            BuildInFnParam(ValueType::String, "spec", false),
+        ],
+        identi: BuildInFnIdenti::Basic(BasicFn::FORMAT),
+    };
+    let out_format = BuildInFunction {
+        params: vec![BuildInFnParam(ValueType::String, "spec", false)],
+        identi: BuildInFnIdenti::Basic(BasicFn::OUTFORMAT),
+    };
+    let to = BuildInFunction {
+        params: vec![
+            BuildInFnParam(ValueType::Void, "input", false),
+            BuildInFnParam(ValueType::String, "unit", false),
+        ],
+        identi: BuildInFnIdenti::Basic(BasicFn::TO),
+    };
     let exit = BuildInFunction {
         params: vec![],
         identi: BuildInFnIdenti::Basic(BasicFn::EXIT),
     };
     let array = BuildInFunction {
-        params: vec![BuildInFnParam(ValueType::Number, "input")],
+        params: vec![BuildInFnParam(ValueType::Void, "input", false)],
         identi: BuildInFnIdenti::Basic(BasicFn::ARRAY),
     };
+    let instance_of = BuildInFunction {
+        params: vec![
+            BuildInFnParam(ValueType::Void, "input", false),
+            BuildInFnParam(ValueType::Class, "class", false),
+        ],
+        identi: BuildInFnIdenti::Basic(BasicFn::INSTANCEOF),
+    };
     let fraction = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Number, "upper"),
-            BuildInFnParam(ValueType::Number, "lower"),
+            BuildInFnParam(ValueType::Number, "upper", false),
+            BuildInFnParam(ValueType::Number, "lower", false),
         ],
         identi: BuildInFnIdenti::Basic(BasicFn::FRACTION),
     };
@@ -58,7 +132,7 @@ pub fn function_list() -> Vec<(String, Value)> {
     // --- --- --- --- --- ---
 
     let function_template = BuildInFunction {
-        params: vec![BuildInFnParam(ValueType::Void, "input")],
+        params: vec![BuildInFnParam(ValueType::Void, "input", false)],
         identi: BuildInFnIdenti::Basic(BasicFn::TYPE),
     };
     let type__ = function_template.clone();
@@ -89,10 +163,42 @@ pub fn function_list() -> Vec<(String, Value)> {
         (String::from("array"), Value::from(array)),
         (String::from("ascii"), Value::from(ascii)),
         (String::from("len"), Value::from(len)),
+        (String::from("instance_of"), Value::from(instance_of)),
+        (String::from("div_mode"), Value::from(div_mode)),
+        (String::from("precision"), Value::from(precision)),
+        (String::from("out_radix"), Value::from(out_radix)),
+        (String::from("bit_width"), Value::from(bit_width)),
+        (String::from("to_radix"), Value::from(to_radix)),
+        (String::from("from_radix"), Value::from(from_radix)),
+        (String::from("format"), Value::from(format)),
+        (String::from("out_format"), Value::from(out_format)),
+        (String::from("to"), Value::from(to)),
         (String::from("exit"), Value::from(exit)),
     ];
 }
 
+fn format_getter(scope: &mut Scope) -> Result<NumberFormat, ()> {
+    let spec_value = get_val("spec", scope)?;
+    let spec_ref = spec_value.get_str()?;
+    let Some(number_format) = NumberFormat::parse(&spec_ref) else {
+        let msg = format!(
+            "invalid format spec '{}', expected `[,][.digits][type]` with type `f`, `s`, `e` or `en`",
+            spec_ref
+        );
+        return Err(syntax_error(&msg)?);
+    };
+    Ok(number_format)
+}
+
+fn radix_getter(scope: &mut Scope) -> Result<u32, ()> {
+    let radix_value = get_val("radix", scope)?;
+    let radix = radix_value.get_i64()?;
+    if !(2..=36).contains(&radix) {
+        return Err(range_error("radix", "2 <= radix <= 36", radix)?);
+    }
+    Ok(radix as u32)
+}
+
 impl BuildInFnCall for BasicFn {
     fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
         let result = match self {
@@ -123,18 +229,157 @@ impl BuildInFnCall for BasicFn {
                 let upper_value = get_val("upper", scope)?;
                 let lower_value = get_val("lower", scope)?;
 
-                if let (Value::Number(Number::Int(upper)), Value::Number(Number::Int(lower))) =
-                    (upper_value, lower_value)
-                {
-                    return Ok(Value::Number(Number::Fraction(upper, lower)));
+                let (Value::Number(upper), Value::Number(lower)) = (upper_value, lower_value)
+                else {
+                    unreachable!()
+                };
+                if upper.is_exact() && lower.is_exact() {
+                    return Ok(Value::Number(Number::reduce(upper, lower)?));
                 } else {
                     return Err(internal_error(
                         InternalComponent::Std,
-                        "two Int typed value is expected",
+                        "two Int or Fraction typed value is expected",
                     )?);
                 }
             }
+            BasicFn::DIVMODE => {
+                let mode_value = get_val("mode", scope)?;
+                let mode_ref = mode_value.get_str()?;
+                let Some(mode) = DivisionMode::from_name(&mode_ref) else {
+                    let msg = format!(
+                        "invalid division mode '{}', expected `fraction`, `float` or `int`",
+                        mode_ref
+                    );
+                    return Err(syntax_error(&msg)?);
+                };
+                unsafe { ENV_OPTION.division_mode = mode };
+                Value::Void(VoidSign::Empty)
+            }
+            BasicFn::PRECISION => {
+                let digits_value = get_val("digits", scope)?;
+                let Value::Number(digits) = digits_value else {
+                    unreachable!()
+                };
+                if digits < Number::Int(0) || digits > Number::Int(MAX_PRECISION as i64) {
+                    let expected = format!("0 <= digits <= {}", MAX_PRECISION);
+                    return Err(range_error("digits", expected, digits)?);
+                }
+                unsafe { ENV_OPTION.precision = digits.int_value() as u64 };
+                // recompute the constants with the new precision
+                scope.global.variables.extend(build_in::math_constants());
+                Value::Void(VoidSign::Empty)
+            }
+            BasicFn::OUTRADIX => {
+                let radix_value = get_val("radix", scope)?;
+                let radix_ref = radix_value.get_str()?;
+                let Some(radix) = radix_from_name(&radix_ref) else {
+                    let msg = format!(
+                        "invalid radix '{}', expected `hex`, `oct`, `bin`, `dec` or a number between 2 and 36",
+                        radix_ref
+                    );
+                    return Err(syntax_error(&msg)?);
+                };
+                unsafe { ENV_OPTION.output_radix = radix };
+                Value::Void(VoidSign::Empty)
+            }
+            BasicFn::BITWIDTH => {
+                let bits_value = get_val("bits", scope)?;
+                let bits = bits_value.get_i64()?;
+                if !(0..=MAX_BIT_WIDTH as i64).contains(&bits) {
+                    let expected = format!("0 <= bits <= {}", MAX_BIT_WIDTH);
+                    return Err(range_error("bits", expected, bits)?);
+                }
+                unsafe { ENV_OPTION.bit_width = bits as u32 };
+                Value::Void(VoidSign::Empty)
+            }
+            BasicFn::TORADIX => {
+                let input_value = get_val("input", scope)?;
+                let radix = radix_getter(scope)?;
+                let Value::Number(input) = input_value else {
+                    unreachable!()
+                };
+                let Some(digits) = input.to_radix_string(radix) else {
+                    return Err(math_error("only integer can be converted to other radix")?);
+                };
+                Value::from(digits)
+            }
+            BasicFn::FROMRADIX => {
+                let input_value = get_val("input", scope)?;
+                let radix = radix_getter(scope)?;
+                let input_ref = input_value.get_str()?;
+
+                // the sign, radix prefix and digit separators are allowed,
+                // example: "-0xFF_FF"
+                let mut digits = input_ref.trim().replace('_', "");
+                let is_negative = digits.starts_with('-');
+                if is_negative {
+                    digits.remove(0);
+                }
+                let prefix = match radix {
+                    16 => Some("0x"),
+                    8 => Some("0o"),
+                    2 => Some("0b"),
+                    _ => None,
+                };
+                if let Some(prefix) = prefix {
+                    if digits.to_lowercase().starts_with(prefix) {
+                        digits.drain(..prefix.len());
+                    }
+                }
+
+                let Some(value) = BigInt::parse_bytes(digits.as_bytes(), radix) else {
+                    return Err(syntax_error("invalid string parse")?);
+                };
+                Value::Number(Number::from(if is_negative { -value } else { value }))
+            }
+            BasicFn::FORMAT => {
+                let input = get_val("input", scope)?;
+                let number_format = format_getter(scope)?;
+                let Value::Number(num) = input else {
+                    unreachable!()
+                };
+                Value::from(number_format.format(&num))
+            }
+            BasicFn::OUTFORMAT => {
+                let number_format = format_getter(scope)?;
+                unsafe { ENV_OPTION.number_format = number_format };
+                Value::Void(VoidSign::Empty)
+            }
+            BasicFn::TO => {
+                let input = get_val("input", scope)?;
+                let unit_value = get_val("unit", scope)?;
+                let unit = unit_parse(&unit_value.get_str()?)?;
+
+                match input {
+                    // attaches unit to number
+                    Value::Number(num) => Value::Quantity(Quantity::new(num, unit)),
+                    Value::Quantity(qty) => Value::Quantity(qty.convert(&unit)?),
+                    _ => {
+                        return Err(type_error(
+                            Some("Build-in function 'to'"),
+                            vec![ValueType::Number, ValueType::Quantity],
+                            input.get_type(),
+                        )?)
+                    }
+                }
+            }
             BasicFn::EXIT => process::exit(0),
+            BasicFn::INSTANCEOF => {
+                let input = get_val("input", scope)?;
+                let Value::Class(target_class) = get_val("class", scope)? else {
+                    unreachable!()
+                };
+                // the instance of subclass is also
+                // regarded as the instance of parent class
+                let is_instance = match input {
+                    Value::Object(obj) => {
+                        let obj_ref = obj.as_ref().borrow();
+                        obj_ref.prototype.is_subclass_of(&target_class)
+                    }
+                    _ => false,
+                };
+                Value::Boolean(is_instance)
+            }
 
             _ => {
                 let input = get_val("input", scope)?;
@@ -147,8 +392,8 @@ impl BuildInFnCall for BasicFn {
                         Value::Number(num) => Value::Number(num.int()),
                         Value::String(str) => {
                             let refer = str.as_ref().borrow();
-                            let i = str_to_num::<i64>(refer)?;
-                            Value::from(i)
+                            let i = str_to_num::<BigInt>(refer)?;
+                            Value::Number(Number::from(i))
                         }
                         Value::Boolean(bool_val) => Value::from(bool_val as i64),
                         _ => {
@@ -163,8 +408,13 @@ impl BuildInFnCall for BasicFn {
                         Value::Number(num) => Value::Number(num.float()),
                         Value::String(str) => {
                             let refer = str.as_ref().borrow();
-                            let f = str_to_num::<f64>(refer)?;
-                            Value::from(f)
+                            if decimal::is_enabled() {
+                                let d = str_to_num::<BigDecimal>(refer)?;
+                                Value::Number(Number::Decimal(decimal::round(d)))
+                            } else {
+                                let f = str_to_num::<f64>(refer)?;
+                                Value::from(f)
+                            }
                         }
                         Value::Boolean(bool_val) => Value::from(bool_val as i64 as f64),
                         _ => {
@@ -177,15 +427,27 @@ impl BuildInFnCall for BasicFn {
                     },
 
                     BasicFn::BOOLEAN => Value::Boolean(input.get_bool()),
-                    BasicFn::STRING => Value::from(input.to_raw_string()),
-                    BasicFn::ARRAY => {
-                        let Value::Number(num) = input else {
-                            unreachable!()
-                        };
-                        let size = num.int_value() as usize;
-                        let arr_literal: ArrayLiteral = vec![Value::from(0); size].into();
-                        Value::from(arr_literal)
-                    }
+                    BasicFn::STRING => match to_string_hook::resolve(&input, scope)? {
+                        // the `to_string` method of the object
+                        Some(str) => Value::from(str),
+                        None => Value::from(input.to_raw_string()),
+                    },
+                    BasicFn::ARRAY => match input {
+                        Value::Number(num) => {
+                            let size = num.int_value() as usize;
+                            let arr_literal: ArrayLiteral = vec![Value::from(0); size].into();
+                            Value::from(arr_literal)
+                        }
+                        // materializes the range
+                        Value::Range(range) => Value::from(range.to_array()),
+                        _ => {
+                            return Err(type_error(
+                                Some("Build-in function 'array'"),
+                                vec![ValueType::Number, ValueType::Range],
+                                input.get_type(),
+                            )?)
+                        }
+                    },
                     BasicFn::ASCII => {
                         let input_ref = input.get_str()?;
                         let Some(first_char) = input_ref.chars().next() else {
@@ -206,6 +468,8 @@ impl BuildInFnCall for BasicFn {
                         } else if let Value::String(str) = input {
                             let refer = str.borrow();
                             Value::Number(Number::Int(refer.len() as i64))
+                        } else if let Value::Range(range) = input {
+                            Value::Number(Number::Int(range.len() as i64))
                         } else {
                             Value::Void(VoidSign::Empty)
                         }
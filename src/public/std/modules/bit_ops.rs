@@ -1,10 +1,18 @@
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
 use super::super::utils::get_val::get_val;
+use crate::public::error::math_error;
 use crate::public::run_time::{build_in::BuildInFnIdenti, scope::Scope};
 use crate::public::value::function::{BuildInFnParam, BuildInFunction};
+use crate::public::value::number::Number;
 use crate::public::value::value::{Value, ValueType};
 
 use super::BuildInFnCall;
 
+// bounds the result of `<<`, which is not limited by `i64` any more
+const MAX_SHIFT: u32 = 1024;
+
 #[derive(PartialEq, Clone)]
 pub enum BitOpsFn {
     AND,
@@ -18,8 +26,8 @@ pub enum BitOpsFn {
 pub fn function_list() -> Vec<(String, Value)> {
     let and = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Number, "num1"),
-            BuildInFnParam(ValueType::Number, "num2"),
+            BuildInFnParam(ValueType::Number, "num1", false),
+            BuildInFnParam(ValueType::Number, "num2", false),
         ],
         identi: BuildInFnIdenti::BitOps(BitOpsFn::AND),
     };
@@ -33,7 +41,7 @@ pub fn function_list() -> Vec<(String, Value)> {
     r_shift.identi = BuildInFnIdenti::BitOps(BitOpsFn::RShift);
 
     let not = BuildInFunction {
-        params: vec![BuildInFnParam(ValueType::Number, "input")],
+        params: vec![BuildInFnParam(ValueType::Number, "input", false)],
         identi: BuildInFnIdenti::BitOps(BitOpsFn::NOT),
     };
 
@@ -47,29 +55,68 @@ pub fn function_list() -> Vec<(String, Value)> {
     ];
 }
 
+// the integer value of the bitwise operand,
+// the non-integer number is not truncated silently.
+fn integer_operand(num: &Number) -> Result<BigInt, ()> {
+    let integer = num.int();
+    match integer {
+        Number::Int(i) if integer == *num => Ok(BigInt::from(i)),
+        Number::BigInt(big) if integer == *num => Ok(big),
+        _ => {
+            let msg = format!("bitwise operands should be integers, found `{}`", num);
+            Err(math_error(&msg)?)
+        }
+    }
+}
+
+impl BitOpsFn {
+    // is also used by the infix bitwise operators,
+    // `num2` is ignored for `NOT`.
+    pub fn compute(&self, num1: &Number, num2: &Number) -> Result<Number, ()> {
+        let int1 = integer_operand(num1)?;
+        if *self == BitOpsFn::NOT {
+            return Ok(Number::from(!int1));
+        }
+
+        let int2 = integer_operand(num2)?;
+        let result = match self {
+            BitOpsFn::AND => int1 & int2,
+            BitOpsFn::OR => int1 | int2,
+            BitOpsFn::XOR => int1 ^ int2,
+            _ => {
+                let shift = int2.to_u32().filter(|shift| *shift <= MAX_SHIFT);
+                let Some(shift) = shift else {
+                    let msg = format!("the shift amount should be between 0 and {}", MAX_SHIFT);
+                    return Err(math_error(&msg)?);
+                };
+                if *self == BitOpsFn::LShift {
+                    int1 << shift
+                } else {
+                    int1 >> shift
+                }
+            }
+        };
+        Ok(Number::from(result))
+    }
+}
+
 impl BuildInFnCall for BitOpsFn {
     fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
         let result = if *self != BitOpsFn::NOT {
             // AND | OR | XOR | LShift | RShift
             let num_val1 = get_val("num1", scope)?;
             let num_val2 = get_val("num2", scope)?;
-            let (compute_num1, compute_num2) = (num_val1.get_i64()?, num_val2.get_i64()?);
-
-            let compute_res = match self {
-                BitOpsFn::AND => compute_num1 & compute_num2,
-                BitOpsFn::OR => compute_num1 | compute_num2,
-                BitOpsFn::XOR => compute_num1 ^ compute_num2,
-                BitOpsFn::LShift => compute_num1 << compute_num2,
-                BitOpsFn::RShift => compute_num1 >> compute_num2,
-                _ => unreachable!(),
+            let (Value::Number(num1), Value::Number(num2)) = (num_val1, num_val2) else {
+                unreachable!()
             };
-            Value::from(compute_res)
+            self.compute(&num1, &num2)?
         } else {
             // NOT
-            let input = get_val("input", scope)?;
-            let compute_num = input.get_i64()?;
-            Value::from(!compute_num)
+            let Value::Number(input) = get_val("input", scope)? else {
+                unreachable!()
+            };
+            self.compute(&input, &Number::Int(0))?
         };
-        return Ok(result);
+        return Ok(Value::Number(result));
     }
 }
@@ -37,20 +37,20 @@ static mut FILE_CLASS: Option<Rc<Class>> = None;
 fn static_class_setter() {
     // file-class methods
     let read = BuildInFunction {
-        params: vec![BuildInFnParam(ValueType::Object, "self")],
+        params: vec![BuildInFnParam(ValueType::Object, "self", false)],
         identi: BuildInFnIdenti::FileSystem(FileSysFn::Read),
     };
     let write = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::String, "content"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::String, "content", false),
         ],
         identi: BuildInFnIdenti::FileSystem(FileSysFn::Write),
     };
     let append = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::String, "content"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::String, "content", false),
         ],
         identi: BuildInFnIdenti::FileSystem(FileSysFn::Append),
     };
@@ -58,8 +58,8 @@ fn static_class_setter() {
     // fs-class methods
     let fs_method_template = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::String, "path"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::String, "path", false),
         ],
         identi: BuildInFnIdenti::FileSystem(FileSysFn::Open),
     };
@@ -1,8 +1,14 @@
 use std::rc::Rc;
 
+use bigdecimal::BigDecimal;
+use num_complex::Complex64;
+
+use crate::public::error::math_error;
+
 use crate::public::run_time::build_in::BuildInFnIdenti;
 use crate::public::run_time::scope::Scope;
 use crate::public::value::array::ArrayLiteral;
+use crate::public::value::decimal;
 use crate::public::value::function::{BuildInFnParam, BuildInFunction, Function};
 use crate::public::value::number::Number;
 use crate::public::value::oop::class::Class;
@@ -35,6 +41,11 @@ pub enum MathFn {
     FLOOR,
     ROUND,
     MOD,
+
+    RE,
+    IM,
+    ARG,
+    CONJ,
 }
 
 static mut MODULE_CLASS: Option<Rc<Class>> = None;
@@ -42,8 +53,8 @@ static mut MODULE_CLASS: Option<Rc<Class>> = None;
 fn static_class_setter() {
     let sin = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::Number, "input"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::Number, "input", false),
         ],
         identi: BuildInFnIdenti::Math(MathFn::SIN),
     };
@@ -65,6 +76,10 @@ fn static_class_setter() {
     let mut sqrt = sin.clone();
     let mut floor = sin.clone();
     let mut round = sin.clone();
+    let mut re = sin.clone();
+    let mut im = sin.clone();
+    let mut arg = sin.clone();
+    let mut conj = sin.clone();
 
     cos.identi = BuildInFnIdenti::Math(MathFn::COS);
     tan.identi = BuildInFnIdenti::Math(MathFn::TAN);
@@ -84,20 +99,24 @@ fn static_class_setter() {
     sqrt.identi = BuildInFnIdenti::Math(MathFn::SQRT);
     floor.identi = BuildInFnIdenti::Math(MathFn::FLOOR);
     round.identi = BuildInFnIdenti::Math(MathFn::ROUND);
+    re.identi = BuildInFnIdenti::Math(MathFn::RE);
+    im.identi = BuildInFnIdenti::Math(MathFn::IM);
+    arg.identi = BuildInFnIdenti::Math(MathFn::ARG);
+    conj.identi = BuildInFnIdenti::Math(MathFn::CONJ);
 
     let log = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::Number, "base"),
-            BuildInFnParam(ValueType::Number, "natural"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::Number, "base", false),
+            BuildInFnParam(ValueType::Number, "natural", false),
         ],
         identi: BuildInFnIdenti::Math(MathFn::LOG),
     };
     let modulo = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::Number, "base"),
-            BuildInFnParam(ValueType::Number, "target"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::Number, "base", false),
+            BuildInFnParam(ValueType::Number, "target", false),
         ],
         identi: BuildInFnIdenti::Math(MathFn::MOD),
     };
@@ -128,6 +147,10 @@ fn static_class_setter() {
                     (String::from("floor"), Function::from(floor)),
                     (String::from("round"), Function::from(round)),
                     (String::from("mod"), Function::from(modulo)),
+                    (String::from("re"), Function::from(re)),
+                    (String::from("im"), Function::from(im)),
+                    (String::from("arg"), Function::from(arg)),
+                    (String::from("conj"), Function::from(conj)),
                 ],
             )
             .into(),
@@ -147,6 +170,69 @@ pub fn module_object() -> Object {
     .unwrap();
 }
 
+fn complex_call(math_fn: &MathFn, c: Complex64) -> Result<Number, ()> {
+    let result = match math_fn {
+        MathFn::SIN => c.sin(),
+        MathFn::COS => c.cos(),
+        MathFn::TAN => c.tan(),
+        MathFn::ASIN => c.asin(),
+        MathFn::ACOS => c.acos(),
+        MathFn::ATAN => c.atan(),
+        MathFn::SINH => c.sinh(),
+        MathFn::COSH => c.cosh(),
+        MathFn::TANH => c.tanh(),
+        MathFn::LOG10 => c.log10(),
+        MathFn::LOG2 => c.log2(),
+        MathFn::LN => c.ln(),
+        MathFn::EXP => c.exp(),
+        MathFn::SQRT => c.sqrt(),
+        MathFn::ABS => return Ok(Number::Float(c.norm())),
+        _ => {
+            return Err(math_error(
+                "complex number is not supported for this function",
+            )?)
+        }
+    };
+    Ok(Number::from(result))
+}
+
+// returns None when the input is out of domain
+fn decimal_call(math_fn: &MathFn, d: &BigDecimal) -> Option<Number> {
+    let result = match math_fn {
+        MathFn::SIN => decimal::sin(d),
+        MathFn::COS => decimal::cos(d),
+        MathFn::TAN => decimal::tan(d),
+        MathFn::ASIN => decimal::asin(d)?,
+        MathFn::ACOS => decimal::acos(d)?,
+        MathFn::ATAN => decimal::atan(d),
+        MathFn::SINH => decimal::sinh(d),
+        MathFn::COSH => decimal::cosh(d),
+        MathFn::TANH => decimal::tanh(d),
+        MathFn::RAD => decimal::rad(d),
+        MathFn::DEG => decimal::deg(d),
+        MathFn::LOG10 => decimal::log(&BigDecimal::from(10), d)?,
+        MathFn::LOG2 => decimal::log(&BigDecimal::from(2), d)?,
+        MathFn::LN => decimal::ln(d)?,
+        MathFn::EXP => decimal::exp(d),
+        MathFn::ABS => d.abs(),
+        MathFn::SQRT => decimal::sqrt(d)?,
+        MathFn::FLOOR => return Some(Number::Decimal(decimal::floor(d)).int()),
+        MathFn::ROUND => return Some(Number::Decimal(decimal::round_half(d)).int()),
+        _ => unreachable!(),
+    };
+    Some(Number::Decimal(result))
+}
+// decimal computing is used when the precision is set
+// or the input is already decimal.
+fn is_decimal_input(num: &Number) -> bool {
+    match num {
+        Number::Decimal(_) => true,
+        Number::Complex(_) | Number::NotANumber => false,
+        Number::Float(f) => f.is_finite() && decimal::is_enabled(),
+        _ => decimal::is_enabled(),
+    }
+}
+
 impl BuildInFnCall for MathFn {
     fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
         let result = match self {
@@ -154,9 +240,22 @@ impl BuildInFnCall for MathFn {
                 let base = get_val("base", scope)?;
                 let natural = get_val("natural", scope)?;
 
-                let base_f = base.get_f64()?;
-                let nature_f = natural.get_f64()?;
-                nature_f.log(base_f)
+                let (Value::Number(base_n), Value::Number(natural_n)) = (&base, &natural) else {
+                    unreachable!()
+                };
+                if is_decimal_input(base_n) && is_decimal_input(natural_n) {
+                    let (base_d, natural_d) = (base_n.decimal_value(), natural_n.decimal_value());
+                    if let Some(res) = decimal::log(&base_d, &natural_d) {
+                        return Ok(Value::Number(Number::Decimal(res)));
+                    }
+                }
+                let res = natural_n.float_value().log(base_n.float_value());
+                if base_n.is_complex() || natural_n.is_complex() || res.is_nan() {
+                    // fallback to complex logarithm
+                    let c = natural_n.complex_value().ln() / base_n.complex_value().ln();
+                    return Ok(Value::Number(Number::from(c)));
+                }
+                res
             }
             MathFn::MOD => {
                 let base = get_val("base", scope)?;
@@ -165,23 +264,57 @@ impl BuildInFnCall for MathFn {
                 let (Value::Number(number1), Value::Number(number2)) = (base, target) else {
                     unreachable!()
                 };
+                if number1.is_complex() || number2.is_complex() {
+                    return Err(math_error(
+                        "complex number is not supported for this function",
+                    )?);
+                }
                 // the latter number can not be ZERO
                 if number2.float_value() == 0.0 {
                     return Ok(Value::Number(Number::NotANumber));
                 }
-                if let (Number::Int(i1), Number::Int(i2)) = (number1, number2) {
+                if let (Number::Int(i1), Number::Int(i2)) = (&number1, &number2) {
                     return Ok(Value::Number(Number::Int(i1 % i2)));
+                } else if is_decimal_input(&number1) && is_decimal_input(&number2) {
+                    let res = number1.decimal_value() % number2.decimal_value();
+                    return Ok(Value::Number(Number::Decimal(decimal::round(res))));
                 } else {
                     let f1 = number1.float_value();
                     let f2 = number2.float_value();
                     f1 % f2
                 }
             }
+            MathFn::RE | MathFn::IM | MathFn::ARG | MathFn::CONJ => {
+                let input = get_val("input", scope)?;
+                let Value::Number(num) = input else {
+                    unreachable!()
+                };
+                let c = num.complex_value();
+
+                return Ok(Value::Number(match self {
+                    MathFn::RE => Number::Float(c.re),
+                    MathFn::IM => Number::Float(c.im),
+                    MathFn::ARG => Number::Float(c.arg()),
+                    MathFn::CONJ => Number::from(c.conj()),
+                    _ => unreachable!(),
+                }));
+            }
             _ => {
                 let input = get_val("input", scope)?;
-                let f = input.get_f64()?;
+                let Value::Number(num) = &input else {
+                    unreachable!()
+                };
+                if num.is_complex() {
+                    return Ok(Value::Number(complex_call(self, num.complex_value())?));
+                }
+                if is_decimal_input(num) {
+                    if let Some(res) = decimal_call(self, &num.decimal_value()) {
+                        return Ok(Value::Number(res));
+                    }
+                }
+                let f = num.float_value();
 
-                match self {
+                let res = match self {
                     MathFn::SIN => f.sin(),
                     MathFn::COS => f.cos(),
                     MathFn::TAN => f.tan(),
@@ -202,7 +335,14 @@ impl BuildInFnCall for MathFn {
                     MathFn::FLOOR => f.floor(),
                     MathFn::ROUND => f.round(),
                     _ => unreachable!(),
+                };
+                // real result is out of domain, example: sqrt(-1), ln(-1)
+                if res.is_nan() && !f.is_nan() {
+                    if let Ok(c) = complex_call(self, Complex64::from(f)) {
+                        return Ok(Value::Number(c));
+                    }
                 }
+                res
             }
         };
         Ok(Value::from(result))
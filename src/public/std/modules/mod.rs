@@ -4,6 +4,7 @@ pub mod bit_ops;
 pub mod file_system;
 pub mod math;
 pub mod string;
+pub mod sym;
 
 use crate::public::run_time::scope::Scope;
 use crate::public::value::value::Value;
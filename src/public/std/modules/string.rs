@@ -17,45 +17,54 @@ pub enum StringFn {
     REPEAT,
     STARTWITH,
     ENDWITH,
+    SLICE,
 }
 
 pub fn module_class() -> Class {
     let split = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::String, "divider"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::String, "divider", false),
         ],
         identi: BuildInFnIdenti::String(StringFn::SPLIT),
     };
     let replace = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::Void, "from"),
-            BuildInFnParam(ValueType::Void, "to"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::Void, "from", false),
+            BuildInFnParam(ValueType::Void, "to", false),
         ],
         identi: BuildInFnIdenti::String(StringFn::REPLACE),
     };
     let repeat = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::Void, "num"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::Void, "num", false),
         ],
         identi: BuildInFnIdenti::String(StringFn::REPEAT),
     };
     let start_with = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::String, "pat"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::String, "pat", false),
         ],
         identi: BuildInFnIdenti::String(StringFn::STARTWITH),
     };
     let end_with = BuildInFunction {
         params: vec![
-            BuildInFnParam(ValueType::Object, "self"),
-            BuildInFnParam(ValueType::String, "pat"),
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::String, "pat", false),
         ],
         identi: BuildInFnIdenti::String(StringFn::ENDWITH),
     };
+    let slice = BuildInFunction {
+        params: vec![
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::Number, "from", false),
+            BuildInFnParam(ValueType::Number, "to", true),
+        ],
+        identi: BuildInFnIdenti::String(StringFn::SLICE),
+    };
 
     return Class::new(
         vec![Property(ValueType::String, String::from("v"))],
@@ -65,6 +74,7 @@ pub fn module_class() -> Class {
             (String::from("repeat"), Function::from(repeat)),
             (String::from("start_with"), Function::from(start_with)),
             (String::from("end_with"), Function::from(end_with)),
+            (String::from("slice"), Function::from(slice)),
         ],
     );
 }
@@ -120,6 +130,20 @@ impl BuildInFnCall for StringFn {
                 };
                 Value::from(result)
             }
+            StringFn::SLICE => {
+                let from_value = get_val("from", scope)?;
+                let to_value = get_val("to", scope)?;
+
+                // slices by characters, `to` defaults to the string length
+                let char_count = str_ref.chars().count();
+                let from = (from_value.get_i64()?.max(0) as usize).min(char_count);
+                let to = match to_value {
+                    Value::Void(_) => char_count,
+                    _ => (to_value.get_i64()?.max(0) as usize).min(char_count),
+                };
+                let sliced_str = str_ref.chars().skip(from).take(to.saturating_sub(from));
+                Value::from(sliced_str.collect::<String>())
+            }
         };
         return Ok(result);
     }
@@ -0,0 +1,548 @@
+use std::fmt;
+
+use crate::public::compile_time::ast::ast_enum::{ASTNode, ASTVec};
+use crate::public::compile_time::ast::types::{
+    ExpressionNode, InvocationNode, NamedParams, ObjectReadingNode, VariableNode,
+};
+use crate::public::error::{math_error, syntax_error};
+use crate::public::value::number::Number;
+use crate::public::value::symbols::Symbols;
+
+const MATH_MODULE_NAME: &str = "Math";
+
+// the symbolic expression tree converted from
+// the RPN sequence of lazy-expression.
+#[derive(PartialEq, Clone)]
+pub enum Expr {
+    Num(Number),
+    Var(String),
+
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Pow(Box<Expr>, Box<Expr>),
+
+    // the function in `Math` module, example: Math.sin(x)
+    Call(String, Box<Expr>),
+}
+
+fn num(value: i64) -> Expr {
+    Expr::Num(Number::Int(value))
+}
+fn add(a: Expr, b: Expr) -> Expr {
+    Expr::Add(a.into(), b.into())
+}
+fn sub(a: Expr, b: Expr) -> Expr {
+    Expr::Sub(a.into(), b.into())
+}
+fn mul(a: Expr, b: Expr) -> Expr {
+    Expr::Mul(a.into(), b.into())
+}
+fn div(a: Expr, b: Expr) -> Expr {
+    Expr::Div(a.into(), b.into())
+}
+fn pow(a: Expr, b: Expr) -> Expr {
+    Expr::Pow(a.into(), b.into())
+}
+fn call(name: &str, a: Expr) -> Expr {
+    Expr::Call(name.to_string(), a.into())
+}
+
+fn unsupported_error() -> Result<Expr, ()> {
+    Err(syntax_error(
+        "unsupported expression for symbolic computing",
+    )?)
+}
+
+impl Expr {
+    pub fn from_node(node: &ASTNode) -> Result<Expr, ()> {
+        match node {
+            ASTNode::Expression(expression_node) => Expr::from_rpn(&expression_node.elements),
+            ASTNode::NumberLiteral(num) => Ok(Expr::Num(num.clone())),
+            ASTNode::Variable(var_node) => Ok(Expr::Var(var_node.name.clone())),
+            ASTNode::Invocation(invocation_node) => {
+                // only the `Math` functions with single param are supported,
+                // the first param is the inserted `self`.
+                let ASTNode::ObjectReading(reading_node) = &invocation_node.caller else {
+                    return unsupported_error();
+                };
+                let ASTNode::Variable(obj_var) = &reading_node.obj_node else {
+                    return unsupported_error();
+                };
+                if obj_var.name != MATH_MODULE_NAME || invocation_node.params.len() != 2 {
+                    return unsupported_error();
+                }
+                let param = Expr::from_rpn(&invocation_node.params[1].elements)?;
+                Ok(call(&reading_node.property, param))
+            }
+            _ => unsupported_error(),
+        }
+    }
+    fn from_rpn(elements: &ASTVec) -> Result<Expr, ()> {
+        let mut expr_stack = Vec::<Expr>::new();
+
+        for node in elements {
+            let ASTNode::SymbolLiteral(symbol) = node else {
+                expr_stack.push(Expr::from_node(node)?);
+                continue;
+            };
+
+            if *symbol == Symbols::Negate {
+                // the prefix minus: -a -> -1 * a
+                let Some(a) = expr_stack.pop() else {
+                    return Err(syntax_error("operating number is missing")?);
+                };
+                expr_stack.push(mul(num(-1), a));
+                continue;
+            }
+
+            let (Some(b), Some(a)) = (expr_stack.pop(), expr_stack.pop()) else {
+                return Err(syntax_error("operating number is missing")?);
+            };
+            let current = match symbol {
+                Symbols::Plus => add(a, b),
+                Symbols::Minus => sub(a, b),
+                Symbols::Multiply => mul(a, b),
+                Symbols::Divide => div(a, b),
+                Symbols::Power => pow(a, b),
+                _ => return unsupported_error(),
+            };
+            expr_stack.push(current);
+        }
+
+        match (expr_stack.pop(), expr_stack.is_empty()) {
+            (Some(expr), true) => Ok(expr),
+            _ => unsupported_error(),
+        }
+    }
+
+    pub fn to_node(&self) -> ASTNode {
+        let mut elements = ASTVec::new();
+        self.to_rpn(&mut elements);
+        ASTNode::Expression(ExpressionNode { elements }.into())
+    }
+    fn to_rpn(&self, elements: &mut ASTVec) {
+        let symbol = match self {
+            Expr::Num(num) => {
+                elements.push(ASTNode::NumberLiteral(num.clone()));
+                return;
+            }
+            Expr::Var(name) => {
+                let var_node = VariableNode { name: name.clone() };
+                elements.push(ASTNode::Variable(var_node.into()));
+                return;
+            }
+            Expr::Call(name, param) => {
+                let math_var = || {
+                    let var_node = VariableNode {
+                        name: String::from(MATH_MODULE_NAME),
+                    };
+                    ASTNode::Variable(var_node.into())
+                };
+                let reading_node = ObjectReadingNode {
+                    obj_node: math_var(),
+                    property: name.clone(),
+                };
+                let mut param_elements = ASTVec::new();
+                param.to_rpn(&mut param_elements);
+
+                let invocation_node = InvocationNode {
+                    caller: ASTNode::ObjectReading(reading_node.into()),
+                    params: vec![
+                        ExpressionNode {
+                            elements: vec![math_var()],
+                        },
+                        ExpressionNode {
+                            elements: param_elements,
+                        },
+                    ],
+                    named_params: NamedParams::new(),
+                };
+                elements.push(ASTNode::Invocation(invocation_node.into()));
+                return;
+            }
+
+            Expr::Add(a, b)
+            | Expr::Sub(a, b)
+            | Expr::Mul(a, b)
+            | Expr::Div(a, b)
+            | Expr::Pow(a, b) => {
+                a.to_rpn(elements);
+                b.to_rpn(elements);
+                match self {
+                    Expr::Add(..) => Symbols::Plus,
+                    Expr::Sub(..) => Symbols::Minus,
+                    Expr::Mul(..) => Symbols::Multiply,
+                    Expr::Div(..) => Symbols::Divide,
+                    Expr::Pow(..) => Symbols::Power,
+                    _ => unreachable!(),
+                }
+            }
+        };
+        elements.push(ASTNode::SymbolLiteral(symbol));
+    }
+
+    // --- --- --- --- --- ---
+
+    fn contains(&self, var: &str) -> bool {
+        match self {
+            Expr::Num(_) => false,
+            Expr::Var(name) => name == var,
+            Expr::Call(_, a) => a.contains(var),
+            Expr::Add(a, b)
+            | Expr::Sub(a, b)
+            | Expr::Mul(a, b)
+            | Expr::Div(a, b)
+            | Expr::Pow(a, b) => a.contains(var) || b.contains(var),
+        }
+    }
+
+    pub fn subs(&self, var: &str, value: &Expr) -> Expr {
+        match self {
+            Expr::Num(_) => self.clone(),
+            Expr::Var(name) => {
+                if name == var {
+                    value.clone()
+                } else {
+                    self.clone()
+                }
+            }
+            Expr::Call(name, a) => call(name, a.subs(var, value)),
+            Expr::Add(a, b) => add(a.subs(var, value), b.subs(var, value)),
+            Expr::Sub(a, b) => sub(a.subs(var, value), b.subs(var, value)),
+            Expr::Mul(a, b) => mul(a.subs(var, value), b.subs(var, value)),
+            Expr::Div(a, b) => div(a.subs(var, value), b.subs(var, value)),
+            Expr::Pow(a, b) => pow(a.subs(var, value), b.subs(var, value)),
+        }
+    }
+
+    // the derivative with respect to `var`
+    pub fn diff(&self, var: &str) -> Result<Expr, ()> {
+        let result = match self {
+            Expr::Num(_) => num(0),
+            Expr::Var(name) => num((name == var) as i64),
+
+            Expr::Add(a, b) => add(a.diff(var)?, b.diff(var)?),
+            Expr::Sub(a, b) => sub(a.diff(var)?, b.diff(var)?),
+            // (uv)' = u'v + uv'
+            Expr::Mul(a, b) => add(mul(a.diff(var)?, *b.clone()), mul(*a.clone(), b.diff(var)?)),
+            // (u/v)' = (u'v - uv') / v^2
+            Expr::Div(a, b) => div(
+                sub(mul(a.diff(var)?, *b.clone()), mul(*a.clone(), b.diff(var)?)),
+                pow(*b.clone(), num(2)),
+            ),
+            Expr::Pow(a, b) => {
+                let (u, v) = (*a.clone(), *b.clone());
+                if !v.contains(var) {
+                    // (u^n)' = n * u^(n - 1) * u'
+                    mul(mul(v.clone(), pow(u, sub(v, num(1)))), a.diff(var)?)
+                } else if !u.contains(var) {
+                    // (a^v)' = a^v * ln(a) * v'
+                    mul(mul(self.clone(), call("ln", u)), b.diff(var)?)
+                } else {
+                    // (u^v)' = u^v * (v' * ln(u) + v * u' / u)
+                    mul(
+                        self.clone(),
+                        add(
+                            mul(b.diff(var)?, call("ln", u.clone())),
+                            div(mul(v, a.diff(var)?), u),
+                        ),
+                    )
+                }
+            }
+
+            Expr::Call(name, a) => {
+                // chain rule: f(u)' = f'(u) * u'
+                let u = *a.clone();
+                let outer = match name.as_str() {
+                    "sin" => call("cos", u),
+                    "cos" => mul(num(-1), call("sin", u)),
+                    "tan" => div(num(1), pow(call("cos", u), num(2))),
+                    "asin" => div(num(1), call("sqrt", sub(num(1), pow(u, num(2))))),
+                    "acos" => div(num(-1), call("sqrt", sub(num(1), pow(u, num(2))))),
+                    "atan" => div(num(1), add(num(1), pow(u, num(2)))),
+                    "sinh" => call("cosh", u),
+                    "cosh" => call("sinh", u),
+                    "tanh" => sub(num(1), pow(call("tanh", u), num(2))),
+                    "exp" => call("exp", u),
+                    "ln" => div(num(1), u),
+                    "log10" => div(num(1), mul(u, call("ln", num(10)))),
+                    "log2" => div(num(1), mul(u, call("ln", num(2)))),
+                    "sqrt" => div(num(1), mul(num(2), call("sqrt", u))),
+                    _ => {
+                        let msg = format!("can not differentiate function `Math.{}`", name);
+                        return Err(math_error(&msg)?);
+                    }
+                };
+                mul(outer, a.diff(var)?)
+            }
+        };
+        Ok(result)
+    }
+
+    // --- --- --- --- --- ---
+
+    pub fn simplify(&self) -> Expr {
+        let mut current = self.clone();
+        loop {
+            let next = current.simplify_once();
+            if next == current {
+                return next;
+            }
+            current = next;
+        }
+    }
+    fn simplify_once(&self) -> Expr {
+        match self {
+            Expr::Num(_) | Expr::Var(_) => self.clone(),
+            Expr::Call(name, a) => call(name, a.simplify_once()),
+            Expr::Add(a, b) => simplify_add(a.simplify_once(), b.simplify_once()),
+            Expr::Sub(a, b) => simplify_sub(a.simplify_once(), b.simplify_once()),
+            Expr::Mul(a, b) => simplify_mul(a.simplify_once(), b.simplify_once()),
+            Expr::Div(a, b) => simplify_div(a.simplify_once(), b.simplify_once()),
+            Expr::Pow(a, b) => simplify_pow(a.simplify_once(), b.simplify_once()),
+        }
+    }
+
+    fn is_num(&self, value: i64) -> bool {
+        matches!(self, Expr::Num(n) if *n == Number::Int(value))
+    }
+    // splits the numeric coefficient, example: 3*x -> (3, x)
+    fn split_coefficient(&self) -> (Number, Expr) {
+        match self {
+            Expr::Mul(a, b) => match a.as_ref() {
+                Expr::Num(n) => (n.clone(), *b.clone()),
+                _ => (Number::Int(1), self.clone()),
+            },
+            _ => (Number::Int(1), self.clone()),
+        }
+    }
+    // splits the base and the exponent, example: x^2 -> (x, 2)
+    fn split_power(&self) -> (Expr, Expr) {
+        match self {
+            Expr::Pow(a, b) => (*a.clone(), *b.clone()),
+            _ => (self.clone(), num(1)),
+        }
+    }
+
+    // the priority used to add parentheses when displaying
+    fn priority(&self) -> u8 {
+        match self {
+            Expr::Add(..) | Expr::Sub(..) => 1,
+            Expr::Mul(..) | Expr::Div(..) => 2,
+            Expr::Pow(..) => 3,
+            Expr::Num(n) if *n < Number::Int(0) => 1,
+            Expr::Num(_) | Expr::Var(_) | Expr::Call(..) => 4,
+        }
+    }
+}
+
+fn simplify_add(a: Expr, b: Expr) -> Expr {
+    if let (Expr::Num(n1), Expr::Num(n2)) = (&a, &b) {
+        return Expr::Num(n1.clone() + n2.clone());
+    }
+    if a.is_num(0) {
+        return b;
+    }
+    if b.is_num(0) {
+        return a;
+    }
+    // (x - y) + y -> x
+    if let Expr::Sub(x, y) = &a {
+        if y.as_ref() == &b {
+            return *x.clone();
+        }
+    }
+    // keeps the constant term at the end, example: 3 + x -> x + 3
+    if let Expr::Num(_) = a {
+        return add(b, a);
+    }
+    // (x + 1) + 2 -> x + 3
+    if let (Expr::Add(x, c1), Expr::Num(c2)) = (&a, &b) {
+        if let Expr::Num(c1) = c1.as_ref() {
+            return add(*x.clone(), Expr::Num(c1.clone() + c2.clone()));
+        }
+    }
+    // like terms, example: 2*x + 3*x -> 5*x
+    let ((c1, term1), (c2, term2)) = (a.split_coefficient(), b.split_coefficient());
+    if term1 == term2 {
+        return simplify_mul(Expr::Num(c1 + c2), term1);
+    }
+    add(a, b)
+}
+fn simplify_sub(a: Expr, b: Expr) -> Expr {
+    if let (Expr::Num(n1), Expr::Num(n2)) = (&a, &b) {
+        return Expr::Num(n1.clone() - n2.clone());
+    }
+    if b.is_num(0) {
+        return a;
+    }
+    if a.is_num(0) {
+        return simplify_mul(num(-1), b);
+    }
+    // (x + y) - y -> x
+    if let Expr::Add(x, y) = &a {
+        if y.as_ref() == &b {
+            return *x.clone();
+        }
+    }
+    let ((c1, term1), (c2, term2)) = (a.split_coefficient(), b.split_coefficient());
+    if term1 == term2 {
+        return simplify_mul(Expr::Num(c1 - c2), term1);
+    }
+    sub(a, b)
+}
+fn simplify_mul(a: Expr, b: Expr) -> Expr {
+    if let (Expr::Num(n1), Expr::Num(n2)) = (&a, &b) {
+        return Expr::Num(n1.clone() * n2.clone());
+    }
+    if a.is_num(0) || b.is_num(0) {
+        return num(0);
+    }
+    if a.is_num(1) {
+        return b;
+    }
+    if b.is_num(1) {
+        return a;
+    }
+    // keeps the coefficient at the beginning, example: x*3 -> 3*x
+    if let Expr::Num(_) = b {
+        return mul(b, a);
+    }
+    // 2*(3*x) -> 6*x
+    if let (Expr::Num(c1), Expr::Mul(c2, x)) = (&a, &b) {
+        if let Expr::Num(c2) = c2.as_ref() {
+            return mul(Expr::Num(c1.clone() * c2.clone()), *x.clone());
+        }
+    }
+    // (2*x)*y -> 2*(x*y)
+    if let Expr::Mul(c, x) = &a {
+        if let Expr::Num(_) = c.as_ref() {
+            return mul(*c.clone(), simplify_mul(*x.clone(), b));
+        }
+    }
+    // x*(2*y) -> 2*(x*y)
+    if let Expr::Mul(c, y) = &b {
+        if let Expr::Num(_) = c.as_ref() {
+            return mul(*c.clone(), simplify_mul(a, *y.clone()));
+        }
+    }
+    // same base, example: x * x^2 -> x^3
+    let ((base1, exp1), (base2, exp2)) = (a.split_power(), b.split_power());
+    if base1 == base2 {
+        return simplify_pow(base1, simplify_add(exp1, exp2));
+    }
+    mul(a, b)
+}
+fn simplify_div(a: Expr, b: Expr) -> Expr {
+    if b.is_num(0) {
+        return div(a, b);
+    }
+    if let (Expr::Num(n1), Expr::Num(n2)) = (&a, &b) {
+        return Expr::Num(n1.clone() / n2.clone());
+    }
+    if a.is_num(0) {
+        return num(0);
+    }
+    if b.is_num(1) {
+        return a;
+    }
+    if a == b {
+        return num(1);
+    }
+    // (6*x) / 2 -> 3*x
+    if let (Expr::Mul(c1, x), Expr::Num(c2)) = (&a, &b) {
+        if let Expr::Num(c1) = c1.as_ref() {
+            return simplify_mul(Expr::Num(c1.clone() / c2.clone()), *x.clone());
+        }
+    }
+    div(a, b)
+}
+fn simplify_pow(a: Expr, b: Expr) -> Expr {
+    if let (Expr::Num(n1), Expr::Num(n2)) = (&a, &b) {
+        if !(n1.is_zero() && *n2 < Number::Int(0)) {
+            return Expr::Num(n1.clone().pow(n2.clone()));
+        }
+    }
+    if b.is_num(0) || a.is_num(1) {
+        return num(1);
+    }
+    if b.is_num(1) {
+        return a;
+    }
+    // (x^2)^3 -> x^6
+    if let (Expr::Pow(x, m), Expr::Num(n)) = (&a, &b) {
+        if let Expr::Num(m) = m.as_ref() {
+            return simplify_pow(*x.clone(), Expr::Num(m.clone() * n.clone()));
+        }
+    }
+    pow(a, b)
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // wraps the operand with parentheses when needed
+        fn operand(expr: &Expr, min_priority: u8) -> String {
+            if expr.priority() < min_priority {
+                format!("({})", expr)
+            } else {
+                expr.to_string()
+            }
+        }
+
+        match self {
+            Expr::Num(num) => write!(f, "{}", num),
+            Expr::Var(name) => write!(f, "{}", name),
+            Expr::Call(name, a) => write!(f, "{}.{}({})", MATH_MODULE_NAME, name, a),
+
+            Expr::Add(a, b) => write!(f, "{} + {}", a, operand(b, 1)),
+            Expr::Sub(a, b) => write!(f, "{} - {}", a, operand(b, 2)),
+            Expr::Mul(a, b) => {
+                if a.is_num(-1) {
+                    return write!(f, "-{}", operand(b, 3));
+                }
+                // the negative coefficient needs no parentheses
+                let left = match a.as_ref() {
+                    Expr::Num(_) => a.to_string(),
+                    _ => operand(a, 2),
+                };
+                // multiplication is associative
+                let right = match b.as_ref() {
+                    Expr::Mul(..) => b.to_string(),
+                    _ => operand(b, 3),
+                };
+                write!(f, "{}*{}", left, right)
+            }
+            Expr::Div(a, b) => {
+                let left = match a.as_ref() {
+                    Expr::Num(_) => a.to_string(),
+                    _ => operand(a, 2),
+                };
+                write!(f, "{}/{}", left, operand(b, 3))
+            }
+            Expr::Pow(a, b) => write!(f, "{}^{}", operand(a, 4), operand(b, 3)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var(name: &str) -> ASTNode {
+        let var_node = VariableNode {
+            name: name.to_string(),
+        };
+        ASTNode::Variable(var_node.into())
+    }
+
+    #[test]
+    fn negation_is_kept_from_rpn() {
+        // the RPN of `-x`
+        let elements = vec![var("x"), ASTNode::SymbolLiteral(Symbols::Negate)];
+        let expr = Expr::from_rpn(&elements).unwrap();
+        assert!(expr == mul(num(-1), Expr::Var(String::from("x"))));
+        assert!(expr.diff("x").unwrap().simplify() == num(-1));
+    }
+}
@@ -0,0 +1,119 @@
+mod expr;
+
+use std::ptr::addr_of;
+use std::rc::Rc;
+
+use crate::public::error::type_error;
+use crate::public::run_time::build_in::BuildInFnIdenti;
+use crate::public::run_time::scope::Scope;
+use crate::public::std::utils::get_val::get_val;
+use crate::public::value::array::ArrayLiteral;
+use crate::public::value::function::{BuildInFnParam, BuildInFunction, Function};
+use crate::public::value::oop::class::Class;
+use crate::public::value::oop::object::Object;
+use crate::public::value::value::{Value, ValueType};
+
+use self::expr::Expr;
+
+use super::BuildInFnCall;
+
+#[derive(PartialEq, Clone)]
+pub enum SymFn {
+    Diff,
+    Simplify,
+    Subs,
+    Str,
+}
+
+static mut MODULE_CLASS: Option<Rc<Class>> = None;
+
+fn static_class_setter() {
+    let diff = BuildInFunction {
+        params: vec![
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::LazyExpression, "expr", false),
+            BuildInFnParam(ValueType::String, "var", false),
+        ],
+        identi: BuildInFnIdenti::Sym(SymFn::Diff),
+    };
+    let simplify = BuildInFunction {
+        params: vec![
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::LazyExpression, "expr", false),
+        ],
+        identi: BuildInFnIdenti::Sym(SymFn::Simplify),
+    };
+    let mut str = simplify.clone();
+    str.identi = BuildInFnIdenti::Sym(SymFn::Str);
+    let subs = BuildInFunction {
+        params: vec![
+            BuildInFnParam(ValueType::Object, "self", false),
+            BuildInFnParam(ValueType::LazyExpression, "expr", false),
+            BuildInFnParam(ValueType::String, "var", false),
+            BuildInFnParam(ValueType::Void, "value", false),
+        ],
+        identi: BuildInFnIdenti::Sym(SymFn::Subs),
+    };
+
+    unsafe {
+        MODULE_CLASS = Some(
+            Class::new(
+                vec![],
+                vec![
+                    (String::from("diff"), Function::from(diff)),
+                    (String::from("simplify"), Function::from(simplify)),
+                    (String::from("subs"), Function::from(subs)),
+                    (String::from("str"), Function::from(str)),
+                ],
+            )
+            .into(),
+        )
+    }
+}
+
+pub fn module_object() -> Object {
+    if unsafe { (*addr_of!(MODULE_CLASS)).is_none() } {
+        static_class_setter();
+    }
+
+    Class::instantiate(
+        unsafe { (*addr_of!(MODULE_CLASS)).as_ref().unwrap().clone() },
+        ArrayLiteral::new(),
+    )
+    .unwrap()
+}
+
+fn expr_getter(name: &str, scope: &mut Scope) -> Result<Expr, ()> {
+    match get_val(name, scope)? {
+        Value::LazyExpression(le_body) => Expr::from_node(&le_body),
+        Value::Number(num) => Ok(Expr::Num(num)),
+        other => Err(type_error(
+            Some(name),
+            vec![ValueType::LazyExpression, ValueType::Number],
+            other.get_type(),
+        )?),
+    }
+}
+
+impl BuildInFnCall for SymFn {
+    fn call(&self, scope: &mut Scope) -> Result<Value, ()> {
+        let expr = expr_getter("expr", scope)?;
+
+        let result = match self {
+            SymFn::Diff => {
+                let var = get_val("var", scope)?;
+                let var_name = var.get_str()?.clone();
+                expr.diff(&var_name)?.simplify()
+            }
+            SymFn::Simplify => expr.simplify(),
+            SymFn::Subs => {
+                let var = get_val("var", scope)?;
+                let var_name = var.get_str()?.clone();
+                let value = expr_getter("value", scope)?;
+                expr.subs(&var_name, &value).simplify()
+            }
+            SymFn::Str => return Ok(Value::from(expr.to_string())),
+        };
+        Ok(Value::from(result.to_node()))
+    }
+}
@@ -4,8 +4,8 @@ use crate::public::value::value::Value;
 
 // used to get value of function actual param.
 pub fn get_val(val_name: &str, scope: &mut Scope) -> Result<Value, ()> {
-    let option_value = scope.local.as_ref().unwrap().variables.get(val_name);
-    match option_value {
+    let local_scope = scope.local.as_ref().unwrap().borrow();
+    match local_scope.variables.get(val_name) {
         Some(val) => Ok(val.clone()),
         None => {
             let msg = format!("build-in function param '{}' is missing", val_name);
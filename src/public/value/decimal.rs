@@ -0,0 +1,354 @@
+// arbitrary-precision decimal arithmetic,
+// used when the precision is set with `--precision` or `precision(digits)`.
+
+use bigdecimal::{BigDecimal, Context, RoundingMode};
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+
+use crate::public::env::ENV_OPTION;
+
+// the precision used for decimal values computed
+// while the decimal mode is disabled.
+const DEFAULT_PRECISION: u64 = 16;
+// extra digits kept in the intermediate results
+const GUARD_DIGITS: u64 = 10;
+
+pub fn is_enabled() -> bool {
+    unsafe { ENV_OPTION.precision > 0 }
+}
+// count of significant digits of decimal results
+pub fn precision() -> u64 {
+    let precision = unsafe { ENV_OPTION.precision };
+    if precision > 0 {
+        precision
+    } else {
+        DEFAULT_PRECISION
+    }
+}
+
+fn working_precision() -> u64 {
+    precision() + GUARD_DIGITS
+}
+fn context(prec: u64) -> Context {
+    Context::default().with_prec(prec).unwrap()
+}
+// returns `numer / 10 ^ scale`
+fn decimal(numer: i64, scale: i64) -> BigDecimal {
+    BigDecimal::new(BigInt::from(numer), scale)
+}
+// checks if `value` is too small to affect
+// a sum computed with precision `prec`.
+fn is_negligible(value: &BigDecimal, prec: u64) -> bool {
+    value.is_zero() || value.abs() < decimal(1, prec as i64 + 2)
+}
+
+// rounds the value to the current precision
+pub fn round(value: BigDecimal) -> BigDecimal {
+    value.with_prec(precision()).normalized()
+}
+
+pub fn from_f64(value: f64) -> Option<BigDecimal> {
+    if !value.is_finite() {
+        return None;
+    }
+    // use the shortest representation,
+    // example: 0.1 -> "0.1" rather than "0.1000000000000000055..."
+    value.to_string().parse::<BigDecimal>().ok()
+}
+pub fn from_ratio(numer: &BigInt, denom: &BigInt) -> BigDecimal {
+    let numer = BigDecimal::from_bigint(numer.clone(), 0);
+    let denom = BigDecimal::from_bigint(denom.clone(), 0);
+    round(div_with(&numer, &denom, working_precision()))
+}
+
+// `divisor` is expected to be non-zero
+pub fn div(dividend: &BigDecimal, divisor: &BigDecimal) -> BigDecimal {
+    round(div_with(dividend, divisor, working_precision()))
+}
+fn div_with(dividend: &BigDecimal, divisor: &BigDecimal, prec: u64) -> BigDecimal {
+    let inverse = divisor.inverse_with_context(&context(prec));
+    (dividend * inverse).with_prec(prec)
+}
+
+pub fn powi(base: &BigDecimal, exp: i64) -> BigDecimal {
+    round(base.powi_with_context(exp, &context(working_precision())))
+}
+// `base` is expected to be positive
+pub fn powf(base: &BigDecimal, exp: &BigDecimal) -> BigDecimal {
+    let prec = working_precision() + GUARD_DIGITS;
+    round(exp_with(&(ln_with(base, prec) * exp).with_prec(prec), prec))
+}
+
+// --- --- --- --- --- ---
+
+pub fn pi() -> BigDecimal {
+    round(pi_with(working_precision()))
+}
+pub fn e() -> BigDecimal {
+    round(exp_with(&BigDecimal::one(), working_precision()))
+}
+
+// Machin's formula:
+// PI = 16 * atan(1 / 5) - 4 * atan(1 / 239)
+fn pi_with(prec: u64) -> BigDecimal {
+    let prec = prec + 2;
+    let atan_1_5 = atan_taylor(&decimal(2, 1), prec);
+    let atan_1_239 = atan_taylor(&div_with(&BigDecimal::one(), &decimal(239, 0), prec), prec);
+    (atan_1_5 * decimal(16, 0) - atan_1_239 * decimal(4, 0)).with_prec(prec)
+}
+
+// atan(x) = x - x^3 / 3 + x^5 / 5 - ...
+// converges quickly when |x| is small
+fn atan_taylor(x: &BigDecimal, prec: u64) -> BigDecimal {
+    let x_square = x.square().with_prec(prec);
+    let mut power = x.clone();
+    let mut sum = x.clone();
+    let mut n = 1;
+    loop {
+        power = -(power * &x_square).with_prec(prec);
+        n += 2;
+        let term = div_with(&power, &decimal(n, 0), prec);
+        if is_negligible(&term, prec) {
+            break;
+        }
+        sum += term;
+    }
+    sum.with_prec(prec)
+}
+
+fn exp_with(x: &BigDecimal, prec: u64) -> BigDecimal {
+    if x.is_negative() {
+        return div_with(&BigDecimal::one(), &exp_with(&-x, prec), prec);
+    }
+
+    // e^x = (e^(x / 2^k)) ^ (2^k)
+    let half = decimal(5, 1);
+    let mut reduced = x.clone();
+    let mut k = 0;
+    while reduced > half {
+        reduced = reduced.half();
+        k += 1;
+    }
+    let prec = prec + k / 3 + 1;
+
+    let mut term = BigDecimal::one();
+    let mut sum = BigDecimal::one();
+    let mut n = 0;
+    loop {
+        n += 1;
+        term = div_with(&(term * &reduced), &decimal(n, 0), prec);
+        if is_negligible(&term, prec) {
+            break;
+        }
+        sum += &term;
+    }
+    for _ in 0..k {
+        sum = sum.square().with_prec(prec);
+    }
+    sum
+}
+
+// `x` is expected to be positive
+fn ln_with(x: &BigDecimal, prec: u64) -> BigDecimal {
+    let prec = prec + GUARD_DIGITS;
+
+    // ln(x) = 2^k * ln(x ^ (1 / 2^k)),
+    // takes the square root until x is close to 1
+    let (lower, upper) = (decimal(9, 1), decimal(11, 1));
+    let mut reduced = x.clone();
+    let mut k = 0;
+    while reduced < lower || reduced > upper {
+        reduced = reduced.sqrt_with_context(&context(prec)).unwrap();
+        k += 1;
+    }
+
+    // ln(x) = 2 * atanh((x - 1) / (x + 1))
+    //       = 2 * (z + z^3 / 3 + z^5 / 5 + ...)
+    let z = div_with(
+        &(&reduced - BigDecimal::one()),
+        &(&reduced + BigDecimal::one()),
+        prec,
+    );
+    let z_square = z.square().with_prec(prec);
+    let mut power = z.clone();
+    let mut sum = z;
+    let mut n = 1;
+    loop {
+        power = (power * &z_square).with_prec(prec);
+        n += 2;
+        let term = div_with(&power, &decimal(n, 0), prec);
+        if is_negligible(&term, prec) {
+            break;
+        }
+        sum += term;
+    }
+    (sum * BigDecimal::from(2_i64.pow(k + 1))).with_prec(prec)
+}
+
+// returns `x` reduced into range [-PI, PI]
+fn reduce_angle(x: &BigDecimal, prec: u64) -> BigDecimal {
+    let magnitude = x.order_of_magnitude().max(0) as u64;
+    let prec = prec + magnitude;
+    let two_pi = pi_with(prec).double();
+    let turns = div_with(x, &two_pi, prec).with_scale_round(0, RoundingMode::HalfEven);
+    (x - turns * two_pi).with_prec(prec)
+}
+
+fn sin_with(x: &BigDecimal, prec: u64) -> BigDecimal {
+    let x = reduce_angle(x, prec);
+    let x_square = x.square().with_prec(prec);
+    let mut term = x.clone();
+    let mut sum = x;
+    let mut n = 1;
+    loop {
+        term = -div_with(&(term * &x_square), &decimal((n + 1) * (n + 2), 0), prec);
+        n += 2;
+        if is_negligible(&term, prec) {
+            break;
+        }
+        sum += &term;
+    }
+    sum
+}
+fn cos_with(x: &BigDecimal, prec: u64) -> BigDecimal {
+    let x = reduce_angle(x, prec);
+    let x_square = x.square().with_prec(prec);
+    let mut term = BigDecimal::one();
+    let mut sum = BigDecimal::one();
+    let mut n = 0;
+    loop {
+        term = -div_with(&(term * &x_square), &decimal((n + 1) * (n + 2), 0), prec);
+        n += 2;
+        if is_negligible(&term, prec) {
+            break;
+        }
+        sum += &term;
+    }
+    sum
+}
+
+fn atan_with(x: &BigDecimal, prec: u64) -> BigDecimal {
+    if x.is_negative() {
+        return -atan_with(&-x, prec);
+    }
+    // atan(x) = PI / 2 - atan(1 / x)
+    if x > &BigDecimal::one() {
+        let inverse = div_with(&BigDecimal::one(), x, prec);
+        return pi_with(prec).half() - atan_with(&inverse, prec);
+    }
+
+    // atan(x) = 2 * atan(x / (1 + sqrt(1 + x^2)))
+    let limit = decimal(1, 1);
+    let mut reduced = x.clone();
+    let mut k = 0;
+    while reduced > limit {
+        let root = (BigDecimal::one() + reduced.square())
+            .sqrt_with_context(&context(prec))
+            .unwrap();
+        reduced = div_with(&reduced, &(BigDecimal::one() + root), prec);
+        k += 1;
+    }
+    (atan_taylor(&reduced, prec) * BigDecimal::from(2_i64.pow(k))).with_prec(prec)
+}
+
+// --- --- --- --- --- ---
+
+// returns None when the input is out of domain
+pub fn sqrt(x: &BigDecimal) -> Option<BigDecimal> {
+    if x.is_negative() {
+        return None;
+    }
+    Some(round(x.sqrt_with_context(&context(working_precision()))?))
+}
+pub fn exp(x: &BigDecimal) -> BigDecimal {
+    round(exp_with(x, working_precision()))
+}
+pub fn ln(x: &BigDecimal) -> Option<BigDecimal> {
+    if !x.is_positive() {
+        return None;
+    }
+    Some(round(ln_with(x, working_precision())))
+}
+pub fn log(base: &BigDecimal, x: &BigDecimal) -> Option<BigDecimal> {
+    if !base.is_positive() || !x.is_positive() || base.is_one() {
+        return None;
+    }
+    let prec = working_precision();
+    Some(round(div_with(
+        &ln_with(x, prec),
+        &ln_with(base, prec),
+        prec,
+    )))
+}
+
+pub fn sin(x: &BigDecimal) -> BigDecimal {
+    round(sin_with(x, working_precision()))
+}
+pub fn cos(x: &BigDecimal) -> BigDecimal {
+    round(cos_with(x, working_precision()))
+}
+pub fn tan(x: &BigDecimal) -> BigDecimal {
+    let prec = working_precision();
+    round(div_with(&sin_with(x, prec), &cos_with(x, prec), prec))
+}
+pub fn atan(x: &BigDecimal) -> BigDecimal {
+    round(atan_with(x, working_precision()))
+}
+pub fn asin(x: &BigDecimal) -> Option<BigDecimal> {
+    let prec = working_precision();
+    let one = BigDecimal::one();
+    if x.abs() > one {
+        return None;
+    }
+    if x.abs() == one {
+        let half_pi = pi_with(prec).half();
+        return Some(round(if x.is_negative() { -half_pi } else { half_pi }));
+    }
+    // asin(x) = atan(x / sqrt(1 - x^2))
+    let root = (one - x.square())
+        .sqrt_with_context(&context(prec))
+        .unwrap();
+    Some(round(atan_with(&div_with(x, &root, prec), prec)))
+}
+pub fn acos(x: &BigDecimal) -> Option<BigDecimal> {
+    // acos(x) = PI / 2 - asin(x)
+    let asin_value = asin(x)?;
+    Some(round(pi_with(working_precision()).half() - asin_value))
+}
+
+pub fn sinh(x: &BigDecimal) -> BigDecimal {
+    let prec = working_precision();
+    let exp_value = exp_with(x, prec);
+    let inverse = div_with(&BigDecimal::one(), &exp_value, prec);
+    round((exp_value - inverse).half())
+}
+pub fn cosh(x: &BigDecimal) -> BigDecimal {
+    let prec = working_precision();
+    let exp_value = exp_with(x, prec);
+    let inverse = div_with(&BigDecimal::one(), &exp_value, prec);
+    round((exp_value + inverse).half())
+}
+pub fn tanh(x: &BigDecimal) -> BigDecimal {
+    // tanh(x) = (e^2x - 1) / (e^2x + 1)
+    let prec = working_precision();
+    let exp_value = exp_with(&x.double(), prec);
+    let one = BigDecimal::one();
+    round(div_with(&(&exp_value - &one), &(exp_value + one), prec))
+}
+
+pub fn rad(x: &BigDecimal) -> BigDecimal {
+    let prec = working_precision();
+    round(div_with(&(x * pi_with(prec)), &decimal(180, 0), prec))
+}
+pub fn deg(x: &BigDecimal) -> BigDecimal {
+    let prec = working_precision();
+    round(div_with(&(x * decimal(180, 0)), &pi_with(prec), prec))
+}
+
+pub fn floor(x: &BigDecimal) -> BigDecimal {
+    x.with_scale_round(0, RoundingMode::Floor)
+}
+// rounds half away from zero, same as `f64::round`
+pub fn round_half(x: &BigDecimal) -> BigDecimal {
+    x.with_scale_round(0, RoundingMode::HalfUp)
+}
@@ -1,18 +1,20 @@
-use std::borrow::Borrow;
+use std::cell::RefCell;
 use std::fmt;
 use std::rc::Rc;
 
 use crate::public::compile_time::ast::ast_enum::ASTVec;
-use crate::public::compile_time::ast::types::ExpressionNode;
-use crate::public::error::{range_error, type_error};
+use crate::public::compile_time::ast::types::{ExpressionNode, NamedParams};
+use crate::public::error::{range_error, syntax_error, type_error};
 use crate::public::run_time::build_in::BuildInFnIdenti;
 use crate::public::run_time::scope::{LocalScope, Scope};
 use crate::public::Param;
 
-use super::value::{Value, ValueType};
+use super::array::ArrayLiteral;
+use super::value::{Value, ValueType, VoidSign};
 
+// (type, identi, is_optional)
 #[derive(PartialEq, Clone)]
-pub struct BuildInFnParam(pub ValueType, pub &'static str);
+pub struct BuildInFnParam(pub ValueType, pub &'static str, pub bool);
 
 impl Param for BuildInFnParam {
     fn type__(&self) -> ValueType {
@@ -21,6 +23,9 @@ impl Param for BuildInFnParam {
     fn identi(&self) -> &str {
         self.1
     }
+    fn is_optional(&self) -> bool {
+        self.2
+    }
 }
 
 #[derive(PartialEq, Clone)]
@@ -33,6 +38,10 @@ pub struct BuildInFunction {
 pub struct UserDefinedFnParam {
     pub type__: ValueType,
     pub identi: String,
+    // the default value expression is evaluated
+    // when the param is omitted in invocation.
+    pub default: Option<ExpressionNode>,
+    pub is_rest: bool,
 }
 impl Param for UserDefinedFnParam {
     fn type__(&self) -> ValueType {
@@ -41,11 +50,32 @@ impl Param for UserDefinedFnParam {
     fn identi(&self) -> &str {
         &self.identi
     }
+    fn is_optional(&self) -> bool {
+        self.default.is_some()
+    }
+    fn default_value(&self) -> Option<&ExpressionNode> {
+        self.default.as_ref()
+    }
+    fn is_rest(&self) -> bool {
+        self.is_rest
+    }
 }
-#[derive(PartialEq)]
 pub struct UserDefinedFunction {
     pub params: Vec<UserDefinedFnParam>,
     pub body: ASTVec,
+    // the captured local scope where the function is defined
+    pub closure: Option<Rc<RefCell<LocalScope>>>,
+}
+impl PartialEq for UserDefinedFunction {
+    fn eq(&self, other: &Self) -> bool {
+        // the captured scope is compared by reference
+        let is_same_closure = match (&self.closure, &other.closure) {
+            (Some(scope1), Some(scope2)) => Rc::ptr_eq(scope1, scope2),
+            (None, None) => true,
+            _ => false,
+        };
+        self.params == other.params && self.body == other.body && is_same_closure
+    }
 }
 
 // --- --- --- --- --- ---
@@ -60,30 +90,136 @@ impl Function {
     pub fn param_check(
         formal_params: &Vec<impl Param>,
         actual_params: &Vec<ExpressionNode>,
+        named_params: &NamedParams,
         whole_scope: &mut Scope,
-        local_scope: &mut LocalScope,
+        local_scope: &Rc<RefCell<LocalScope>>,
         expr_resolver: fn(&ExpressionNode, &mut Scope) -> Result<Value, ()>,
     ) -> Result<(), ()> {
-        if actual_params.len() < formal_params.len() {
-            // if param missing
-            return Err(range_error(
-                "function invocation",
-                formal_params.len(),
-                actual_params.len(),
-            )?);
+        // the actual params are evaluated in the caller scope
+        let mut actual_values = vec![];
+        for actual_param_node in actual_params {
+            actual_values.push(expr_resolver(actual_param_node, whole_scope)?);
+        }
+        let mut named_values = vec![];
+        for (name, named_param_node) in named_params {
+            named_values.push((name.clone(), expr_resolver(named_param_node, whole_scope)?));
         }
 
-        let mut index = 0;
-        while index < formal_params.len() {
-            let formal_param = &formal_params[index];
+        Function::param_bind(
+            formal_params,
+            actual_values,
+            named_values,
+            whole_scope,
+            local_scope,
+            expr_resolver,
+        )
+    }
 
+    // binds the computed param values to the local scope
+    pub fn param_bind(
+        formal_params: &Vec<impl Param>,
+        actual_values: Vec<Value>,
+        named_values: Vec<(String, Value)>,
+        whole_scope: &mut Scope,
+        local_scope: &Rc<RefCell<LocalScope>>,
+        expr_resolver: fn(&ExpressionNode, &mut Scope) -> Result<Value, ()>,
+    ) -> Result<(), ()> {
+        // the rest param must be the last one
+        let rest_param = formal_params.last().filter(|param| param.is_rest());
+        let fixed_count = formal_params.len() - rest_param.is_some() as usize;
+        let required_count = formal_params[..fixed_count]
+            .iter()
+            .filter(|param| !param.is_optional())
+            .count();
+
+        let actual_count = actual_values.len();
+
+        // the `self` param of methods is passed implicitly,
+        // and is not counted in the error message.
+        let receiver_count = formal_params
+            .first()
+            .is_some_and(|param| param.identi() == "self") as usize;
+        let shown_actual = actual_count.saturating_sub(receiver_count);
+        let (shown_required, shown_fixed) = (
+            required_count.saturating_sub(receiver_count),
+            fixed_count.saturating_sub(receiver_count),
+        );
+        let expected = if rest_param.is_some() {
+            format!("at least {}", shown_required)
+        } else if shown_required == shown_fixed {
+            shown_required.to_string()
+        } else {
+            format!("{} to {}", shown_required, shown_fixed)
+        };
+        if rest_param.is_none() && actual_count > fixed_count {
+            return Err(range_error("function invocation", expected, shown_actual)?);
+        }
+
+        let mut actual_values = actual_values.into_iter();
+        let mut fixed_values = actual_values
+            .by_ref()
+            .take(fixed_count)
+            .map(Some)
+            .collect::<Vec<Option<Value>>>();
+        fixed_values.resize(fixed_count, None);
+        let rest_values = actual_values.collect::<ArrayLiteral>();
+
+        // match the named params to the fixed formal params
+        let is_named_empty = named_values.is_empty();
+        for (name, value) in named_values {
+            let Some(index) = formal_params[..fixed_count]
+                .iter()
+                .position(|param| param.identi() == name)
+            else {
+                return Err(syntax_error(&format!(
+                    "unknown param `{}` in function invocation",
+                    name
+                ))?);
+            };
+            if index < actual_count {
+                return Err(syntax_error(&format!(
+                    "param `{}` is assigned more than once",
+                    name
+                ))?);
+            }
+            fixed_values[index] = Some(value);
+        }
+
+        for (formal_param, fixed_value) in formal_params[..fixed_count].iter().zip(fixed_values) {
             // compute actual_param_value
-            let actual_param_node = (&actual_params[index]).clone();
-            let actual_param_value = expr_resolver(actual_param_node.borrow(), whole_scope)?;
+            let actual_param_value = match (fixed_value, formal_param.default_value()) {
+                (Some(value), _) => value,
+                (None, Some(default_node)) => {
+                    // the default value is evaluated in the scope of the function,
+                    // where the closure and the former params are visible.
+                    let caller_local = whole_scope.local.replace(local_scope.clone());
+                    let default_value = expr_resolver(default_node, whole_scope);
+                    whole_scope.local = caller_local;
+                    default_value?
+                }
+                (None, None) if formal_param.is_optional() => {
+                    // the omitted optional param without default value
+                    local_scope.borrow_mut().variables.insert(
+                        formal_param.identi().to_string(),
+                        Value::Void(VoidSign::Empty),
+                    );
+                    continue;
+                }
+                (None, None) if is_named_empty => {
+                    return Err(range_error("function invocation", expected, shown_actual)?);
+                }
+                (None, None) => {
+                    return Err(syntax_error(&format!(
+                        "param `{}` is missing in function invocation",
+                        formal_param.identi()
+                    ))?);
+                }
+            };
 
             // param type check
             if actual_param_value.check_type(formal_param.type__()) {
                 local_scope
+                    .borrow_mut()
                     .variables
                     .insert(formal_param.identi().to_string(), actual_param_value);
             } else {
@@ -93,8 +229,13 @@ impl Function {
                     actual_param_value.get_type(),
                 )?
             }
+        }
 
-            index += 1;
+        if let Some(rest_param) = rest_param {
+            local_scope
+                .borrow_mut()
+                .variables
+                .insert(rest_param.identi().to_string(), Value::from(rest_values));
         }
         Ok(())
     }
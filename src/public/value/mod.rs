@@ -1,8 +1,12 @@
 pub mod value;
 
 pub mod array;
+pub mod decimal;
 pub mod function;
 pub mod number;
+pub mod number_format;
+pub mod quantity;
+pub mod range;
 pub mod symbols;
 
 pub mod oop;
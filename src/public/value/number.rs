@@ -1,174 +1,489 @@
-use std::cmp::{self, PartialEq};
+use std::cmp::{Ordering, PartialEq, PartialOrd};
 use std::f64::INFINITY;
 use std::fmt;
 use std::ops::{Add, Div, Mul, Sub};
 
-use crate::public::error::{internal_error, InternalComponent};
+use bigdecimal::{BigDecimal, RoundingMode};
+use num_bigint::BigInt;
+use num_complex::Complex64;
+use num_rational::BigRational;
+use num_traits::{FromPrimitive, Pow, Signed, ToPrimitive, Zero};
+
+use crate::public::env::{DivisionMode, ENV_OPTION};
+use crate::public::error::{internal_error, math_error, InternalComponent};
 use crate::utils::output::print_line__;
 
-#[derive(PartialOrd, Clone, Copy)]
+use super::decimal;
+
+#[derive(Clone)]
 pub enum Number {
     NotANumber,
 
     Int(i64),
+    // integer results that overflow `i64` are promoted to
+    // `Number::BigInt` and demoted back when they fit again.
+    BigInt(BigInt),
     Float(f64),
-    Fraction(i64, i64),
+    // always reduced, with the sign kept in the numerator,
+    // and demoted to integer when the denominator is 1.
+    Fraction(BigRational),
+    // demoted to `Number::Float` when the imaginary part is 0.
+    Complex(Complex64),
+    // arbitrary-precision float, rounded to the current precision,
+    // used instead of `Number::Float` when the precision is set.
+    Decimal(BigDecimal),
 }
 
 impl Number {
     pub fn pow(self, target: Number) -> Number {
-        match self {
-            Number::Int(num1) => match target {
+        if Number::is_decimal_operating(&self, &target) {
+            return Number::decimal_pow(&self, &target);
+        }
+        if self.is_complex() || target.is_complex() {
+            if let Number::Int(exp) = target {
+                if let Ok(exp_i32) = i32::try_from(exp) {
+                    return Number::from(self.complex_value().powi(exp_i32));
+                }
+            }
+            return Number::from(self.complex_value().powc(target.complex_value()));
+        }
+
+        let result = match self {
+            Number::Int(_) | Number::BigInt(_) => match target {
                 Number::Int(num2) => {
                     if num2 >= 0 {
-                        Number::Int(num1.pow(num2 as u32))
+                        Number::int_pow(&self, num2)
                     } else {
-                        Number::Float((num1 as f64).powi(num2 as i32))
+                        Number::Float(self.float_value().powi(num2 as i32))
                     }
                 }
-                Number::Float(num2) => Number::Float((num1 as f64).powf(num2)),
-                Number::Fraction(_, _) => Number::Float((num1 as f64).powf(target.float_value())),
+                Number::BigInt(_) | Number::Fraction(_) => {
+                    Number::Float(self.float_value().powf(target.float_value()))
+                }
+                Number::Float(num2) => Number::Float(self.float_value().powf(num2)),
                 _ => Number::NotANumber,
             },
             Number::Float(num1) => match target {
                 Number::Int(num2) => Number::Float(num1.powi(num2 as i32)),
                 Number::Float(num2) => Number::Float(num1.powf(num2)),
-                Number::Fraction(_, _) => Number::Float(num1.powf(target.float_value())),
+                Number::BigInt(_) | Number::Fraction(_) => {
+                    Number::Float(num1.powf(target.float_value()))
+                }
                 _ => Number::NotANumber,
             },
-            Number::Fraction(upper, lower) => match target {
-                Number::Int(num2) => {
-                    let upper_powed = upper.pow(num2 as u32);
-                    let lower_powed = lower.pow(num2 as u32);
-                    Number::Fraction(upper_powed, lower_powed).reduce().unwrap()
-                }
+            Number::Fraction(ref ratio) => match target {
+                Number::Int(num2) => match i32::try_from(num2) {
+                    Ok(exp) => Number::from(ratio.pow(exp)),
+                    // the result is too large to compute exactly
+                    Err(_) => Number::Float(self.float_value().powf(num2 as f64)),
+                },
                 Number::Float(num2) => {
                     let f_value = self.float_value();
                     Number::Float(f_value.powf(num2))
                 }
-                Number::Fraction(_, _) => {
+                Number::BigInt(_) | Number::Fraction(_) => {
                     Number::Float(self.float_value().powf(target.float_value()))
                 }
                 _ => Number::NotANumber,
             },
-            Number::NotANumber => Number::NotANumber,
+            _ => Number::NotANumber,
+        };
+
+        // the power of negative number may be complex,
+        // example: (-1) ^ 0.5 -> (0 + 1i)
+        if let Number::Float(f) = result {
+            if f.is_nan() && self.float_value() < 0.0 {
+                return Number::from(self.complex_value().powc(target.complex_value()));
+            }
+            // the inexact result of exact numbers
+            if f.is_finite() && decimal::is_enabled() && self.is_exact() && target.is_exact() {
+                return Number::decimal_pow(&self, &target);
+            }
+        }
+        result
+    }
+    fn decimal_pow(base: &Number, exp: &Number) -> Number {
+        if let Number::Int(exp_i) = exp {
+            return Number::Decimal(decimal::powi(&base.decimal_value(), *exp_i));
+        }
+        let base_d = base.decimal_value();
+        if base_d.is_zero() {
+            return Number::Decimal(BigDecimal::zero());
+        }
+        if base_d.is_negative() {
+            // the result is complex
+            return Number::from(base.complex_value().powc(exp.complex_value()));
+        }
+        Number::Decimal(decimal::powf(&base_d, &exp.decimal_value()))
+    }
+
+    // the floored division, example: -7 // 2 -> -4
+    pub fn floor_div(self, other: Number) -> Number {
+        if matches!(
+            (&self, &other),
+            (Number::NotANumber, _) | (_, Number::NotANumber)
+        ) {
+            return Number::NotANumber;
+        }
+
+        if Number::is_decimal_operating(&self, &other) {
+            let quotient = decimal::div(&self.decimal_value(), &other.decimal_value());
+            return Number::Decimal(decimal::floor(&quotient)).int();
+        }
+        if Number::is_float_operating(&self, &other) {
+            return Number::Float((self.float_value() / other.float_value()).floor());
+        }
+
+        if let (Number::Int(num1), Number::Int(num2)) = (&self, &other) {
+            if let (Some(quotient), Some(rem)) = (num1.checked_div(*num2), num1.checked_rem(*num2))
+            {
+                let is_inexact = rem != 0 && (rem < 0) != (*num2 < 0);
+                return Number::Int(quotient - is_inexact as i64);
+            }
         }
+        Number::from((self.rational_value() / other.rational_value()).floor())
+    }
+    // the floored modulo, the result has the same sign as the divisor,
+    // example: -7 % 3 -> 2
+    pub fn modulo(self, other: Number) -> Number {
+        if matches!(
+            (&self, &other),
+            (Number::NotANumber, _) | (_, Number::NotANumber)
+        ) {
+            return Number::NotANumber;
+        }
+
+        if Number::is_decimal_operating(&self, &other) {
+            let quotient = self.clone().floor_div(other.clone());
+            return self - other * quotient;
+        }
+        if Number::is_float_operating(&self, &other) {
+            let (f1, f2) = (self.float_value(), other.float_value());
+            let rem = f1 % f2;
+            if rem != 0.0 && (rem < 0.0) != (f2 < 0.0) {
+                return Number::Float(rem + f2);
+            }
+            return Number::Float(rem);
+        }
+
+        if let (Number::Int(num1), Number::Int(num2)) = (&self, &other) {
+            if let Some(rem) = num1.checked_rem(*num2) {
+                if rem != 0 && (rem < 0) != (*num2 < 0) {
+                    return Number::Int(rem + num2);
+                }
+                return Number::Int(rem);
+            }
+        }
+        let (ratio1, ratio2) = (self.rational_value(), other.rational_value());
+        let quotient = (&ratio1 / &ratio2).floor();
+        Number::from(ratio1 - ratio2 * quotient)
     }
 
     pub fn not(&self) -> Number {
         match self {
             Number::Int(i) => Number::Int(!(*i > 0) as i64),
+            Number::BigInt(i) => Number::Int(!i.is_positive() as i64),
             Number::Float(f) => Number::Int(!(*f > 0.0) as i64),
-            Number::Fraction(upper, _) => {
-                if *upper == 0 {
+            Number::Fraction(ratio) => {
+                if ratio.is_zero() {
                     Number::Int(1)
                 } else {
                     Number::Int(0)
                 }
             }
+            Number::Complex(_) => Number::Int(0),
+            Number::Decimal(d) => Number::Int(!d.is_positive() as i64),
             Number::NotANumber => Number::Int(1),
         }
     }
 
     pub fn int(&self) -> Number {
         match self {
-            Number::Float(f) => Number::Int(*f as i64),
-            Number::Fraction(_, _) => Number::Int(self.int_value()),
+            Number::Float(f) => match BigInt::from_f64(f.trunc()) {
+                Some(big) => Number::from(big),
+                None => Number::NotANumber,
+            },
+            Number::Fraction(ratio) => Number::from(ratio.to_integer()),
+            Number::Complex(c) => Number::from(Complex64::new(c.re.trunc(), c.im.trunc())),
+            Number::Decimal(d) => {
+                let (big, _) = d
+                    .with_scale_round(0, RoundingMode::Down)
+                    .into_bigint_and_exponent();
+                Number::from(big)
+            }
             _ => self.clone(),
         }
     }
     pub fn float(&self) -> Number {
         match self {
-            Number::Int(i) => Number::Float(*i as f64),
-            Number::Fraction(_, _) => Number::Float(self.float_value()),
+            Number::Int(_) | Number::BigInt(_) | Number::Fraction(_) if decimal::is_enabled() => {
+                Number::Decimal(self.decimal_value())
+            }
+            Number::Int(_) | Number::BigInt(_) | Number::Fraction(_) => {
+                Number::Float(self.float_value())
+            }
+            Number::Float(f) if decimal::is_enabled() => match decimal::from_f64(*f) {
+                Some(d) => Number::Decimal(d),
+                None => self.clone(),
+            },
             _ => self.clone(),
         }
     }
 
-    pub fn int_value(self) -> i64 {
+    pub fn int_value(&self) -> i64 {
+        // saturate when the big integer is out of `i64` range
+        fn saturate(big: &BigInt) -> i64 {
+            big.to_i64().unwrap_or(if big.is_negative() {
+                i64::MIN
+            } else {
+                i64::MAX
+            })
+        }
+
         match self {
-            Number::Int(i) => i,
-            Number::Float(f) => f as i64,
-            Number::Fraction(upper, lower) => {
-                if lower > upper {
-                    0
-                } else {
-                    upper / lower
-                }
+            Number::Int(i) => *i,
+            Number::BigInt(i) => saturate(i),
+            Number::Float(f) => *f as i64,
+            Number::Fraction(ratio) => saturate(&ratio.to_integer()),
+            Number::Complex(c) => c.re as i64,
+            Number::Decimal(d) => {
+                d.to_i64()
+                    .unwrap_or(if d.is_negative() { i64::MIN } else { i64::MAX })
             }
             Number::NotANumber => 0,
         }
     }
-    pub fn float_value(self) -> f64 {
+    pub fn float_value(&self) -> f64 {
         match self {
-            Number::Int(i) => i as f64,
-            Number::Float(f) => f,
-            Number::Fraction(upper, lower) => (upper as f64) / (lower as f64),
+            Number::Int(i) => *i as f64,
+            Number::BigInt(i) => i.to_f64().unwrap_or(f64::NAN),
+            Number::Float(f) => *f,
+            Number::Fraction(ratio) => ratio.to_f64().unwrap_or(f64::NAN),
+            Number::Complex(c) => c.re,
+            Number::Decimal(d) => d.to_f64().unwrap_or(f64::NAN),
             Number::NotANumber => 0_f64,
         }
     }
 
-    fn float_cmp(num1: f64, num2: f64) -> bool {
-        const EPS: f64 = f64::EPSILON;
-        let diff = num1 - num2;
-        let diff_abs = diff.abs();
-        return diff_abs <= EPS;
+    // returns the integer digits in `radix`,
+    // example: 255 -> "ff", -8 -> "-10" (radix 8)
+    pub fn to_radix_string(&self, radix: u32) -> Option<String> {
+        if !self.is_integer() {
+            return None;
+        }
+        Some(self.big_int_value().to_str_radix(radix))
+    }
+    // the integer view for the programmer output mode,
+    // example:
+    // 255 -> 0xFF
+    // -1 -> 0b1111_1111 (bit width 8)
+    pub fn to_radix_view(&self, radix: u32, bit_width: u32) -> Option<String> {
+        if !self.is_integer() {
+            return None;
+        }
+        let mut value = self.big_int_value();
+        let mut digits = if bit_width > 0 {
+            // two's-complement
+            let modulus = BigInt::from(1) << bit_width;
+            value = ((value % &modulus) + &modulus) % &modulus;
+            let digit_count = (modulus - BigInt::from(1)).to_str_radix(radix).len();
+            format!(
+                "{:0>width$}",
+                value.to_str_radix(radix),
+                width = digit_count
+            )
+        } else {
+            value.magnitude().to_str_radix(radix)
+        }
+        .to_uppercase();
+
+        if bit_width > 0 {
+            // groups the digits from right to left
+            let group_size = if radix == 2 || radix == 16 { 4 } else { 3 };
+            let chars = digits.chars().collect::<Vec<char>>();
+            let groups = chars
+                .rchunks(group_size)
+                .rev()
+                .map(|group| group.iter().collect::<String>())
+                .collect::<Vec<String>>();
+            digits = groups.join("_");
+        }
+
+        let sign = if value.is_negative() { "-" } else { "" };
+        Some(match radix {
+            16 => format!("{}0x{}", sign, digits),
+            8 => format!("{}0o{}", sign, digits),
+            2 => format!("{}0b{}", sign, digits),
+            10 => format!("{}{}", sign, digits),
+            _ => format!("{}{} (base {})", sign, digits, radix),
+        })
+    }
+    // the output string used by `Value`,
+    // which is affected by the programmer output mode.
+    pub fn output_string(&self) -> String {
+        let (radix, bit_width) = unsafe { (ENV_OPTION.output_radix, ENV_OPTION.bit_width) };
+        if radix == 10 && bit_width == 0 {
+            return self.to_string();
+        }
+        self.to_radix_view(radix, bit_width)
+            .unwrap_or_else(|| self.to_string())
     }
 
-    fn reduce(&self) -> Result<Self, ()> {
-        // this method is specially for Number::Fraction
-        let Number::Fraction(mut upper, mut lower) = self else {
+    // builds the reduced fraction `upper / lower`,
+    // both `upper` and `lower` are expected to be exact numbers.
+    pub fn reduce(upper: Number, lower: Number) -> Result<Number, ()> {
+        if !upper.is_exact() || !lower.is_exact() {
             return Err(internal_error(
                 InternalComponent::InternalFn,
-                "invalid `Number::reduce` invocation"
+                "invalid `Number::reduce` invocation",
             )?);
-        };
-        let gcd_val = Number::gcd(upper, lower);
-        upper /= gcd_val;
-        lower /= gcd_val;
-        return Ok(Number::Fraction(upper, lower));
+        }
+        if lower.is_zero() {
+            return Err(math_error("the denominator should not be ZERO")?);
+        }
+        Ok(Number::from(
+            upper.rational_value() / lower.rational_value(),
+        ))
+    }
+
+    // returns true for Number::Int, Number::BigInt and Number::Fraction
+    pub fn is_exact(&self) -> bool {
+        matches!(
+            self,
+            Number::Int(_) | Number::BigInt(_) | Number::Fraction(_)
+        )
+    }
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Number::Int(i) => *i == 0,
+            Number::BigInt(i) => i.is_zero(),
+            Number::Float(f) => *f == 0.0,
+            Number::Fraction(ratio) => ratio.is_zero(),
+            Number::Complex(c) => c.is_zero(),
+            Number::Decimal(d) => d.is_zero(),
+            Number::NotANumber => false,
+        }
+    }
+    pub fn is_complex(&self) -> bool {
+        matches!(self, Number::Complex(_))
+    }
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, Number::Decimal(_))
+    }
+    pub fn complex_value(&self) -> Complex64 {
+        match self {
+            Number::Complex(c) => *c,
+            _ => Complex64::from(self.float_value()),
+        }
     }
 
-    fn gcd(n1: i64, n2: i64) -> i64 {
-        if n1 == n2 {
-            return 1;
+    // returns the integer value as `BigInt`,
+    // is specially for Number::Int and Number::BigInt
+    fn big_int_value(&self) -> BigInt {
+        match self {
+            Number::Int(i) => BigInt::from(*i),
+            Number::BigInt(i) => i.clone(),
+            _ => unreachable!(),
+        }
+    }
+    // returns the exact value as `BigRational`,
+    // is specially for exact numbers
+    fn rational_value(&self) -> BigRational {
+        match self {
+            Number::Int(_) | Number::BigInt(_) => BigRational::from(self.big_int_value()),
+            Number::Fraction(ratio) => ratio.clone(),
+            _ => unreachable!(),
+        }
+    }
+    // returns the value as `BigDecimal`,
+    // is specially for real numbers except the infinite floats
+    pub fn decimal_value(&self) -> BigDecimal {
+        match self {
+            Number::Int(i) => BigDecimal::from(*i),
+            Number::BigInt(i) => BigDecimal::from_bigint(i.clone(), 0),
+            Number::Float(f) => decimal::from_f64(*f).unwrap_or_default(),
+            Number::Fraction(ratio) => decimal::from_ratio(ratio.numer(), ratio.denom()),
+            Number::Decimal(d) => d.clone(),
+            _ => unreachable!(),
+        }
+    }
+    fn is_integer(&self) -> bool {
+        matches!(self, Number::Int(_) | Number::BigInt(_))
+    }
+
+    fn int_pow(base: &Number, exp: i64) -> Number {
+        if let (Number::Int(num1), Ok(exp_u32)) = (base, u32::try_from(exp)) {
+            if let Some(res) = num1.checked_pow(exp_u32) {
+                return Number::Int(res);
+            }
         }
+        match u32::try_from(exp) {
+            Ok(exp_u32) => Number::from(Pow::pow(base.big_int_value(), exp_u32)),
+            // the result is too large to compute exactly
+            Err(_) => Number::Float(base.float_value().powf(exp as f64)),
+        }
+    }
 
-        // avoid negative number
-        let larger = cmp::max(n1, n2).abs();
-        let smaller = cmp::min(n1, n2).abs();
+    fn float_cmp(num1: f64, num2: f64) -> bool {
+        const EPS: f64 = f64::EPSILON;
+        let diff = num1 - num2;
+        let diff_abs = diff.abs();
+        return diff_abs <= EPS;
+    }
 
-        if smaller == 0 {
-            return larger;
+    // returns true when the operation should be computed
+    // in decimal type.
+    fn is_decimal_operating(num1: &Number, num2: &Number) -> bool {
+        fn is_real(num: &Number) -> bool {
+            match num {
+                Number::Float(f) => f.is_finite(),
+                Number::Complex(_) | Number::NotANumber => false,
+                _ => true,
+            }
         }
+        (num1.is_decimal() || num2.is_decimal()) && is_real(num1) && is_real(num2)
+    }
+    // returns true when the operation should be computed
+    // in float type.
+    fn is_float_operating(num1: &Number, num2: &Number) -> bool {
+        matches!((num1, num2), (Number::Float(_), _) | (_, Number::Float(_)))
+    }
+}
 
-        return Number::gcd(smaller, larger % smaller);
+impl From<BigInt> for Number {
+    fn from(value: BigInt) -> Self {
+        // demote to `Number::Int` when the value fits in i64
+        match value.to_i64() {
+            Some(i) => Number::Int(i),
+            None => Number::BigInt(value),
+        }
+    }
+}
+impl From<Complex64> for Number {
+    fn from(value: Complex64) -> Self {
+        // demote to `Number::Float` when there is no imaginary part
+        if value.im == 0.0 {
+            Number::Float(value.re)
+        } else {
+            Number::Complex(value)
+        }
     }
-    fn lcm(n1: i64, n2: i64) -> i64 {
-        return (n1 * n2) / Number::gcd(n1, n2);
+}
+impl From<BigRational> for Number {
+    fn from(value: BigRational) -> Self {
+        // demote to integer when the denominator is 1
+        if value.is_integer() {
+            Number::from(value.to_integer())
+        } else {
+            Number::Fraction(value)
+        }
     }
 }
 
 impl fmt::Display for Number {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Number::NotANumber => write!(f, "Not a Number"),
-
-            Number::Int(num) => write!(f, "{}", num),
-            Number::Float(num) => {
-                // optimize float number output
-                // example:
-                // 1.0000000000 -> 1
-                if *num == num.floor() {
-                    write!(f, "{}", *num as i64)
-                } else {
-                    write!(f, "{:.10}", num)
-                }
-            }
-            Number::Fraction(upper, lower) => {
-                write!(f, "({} / {})", upper, lower)
-            }
-        }
+        let number_format = unsafe { ENV_OPTION.number_format };
+        write!(f, "{}", number_format.format(self))
     }
 }
 
@@ -176,127 +491,144 @@ impl fmt::Display for Number {
 impl Add for Number {
     type Output = Number;
     fn add(self, other: Number) -> Number {
-        if self == Number::NotANumber || other == Number::NotANumber {
+        if matches!(
+            (&self, &other),
+            (Number::NotANumber, _) | (_, Number::NotANumber)
+        ) {
             return Number::NotANumber;
         }
 
-        if let (Number::Float(_), _) | (_, Number::Float(_)) = (self, other) {
+        if self.is_complex() || other.is_complex() {
+            return Number::from(self.complex_value() + other.complex_value());
+        }
+
+        if Number::is_decimal_operating(&self, &other) {
+            let result = self.decimal_value() + other.decimal_value();
+            return Number::Decimal(decimal::round(result));
+        }
+
+        if Number::is_float_operating(&self, &other) {
             let f1 = self.float_value();
             let f2 = other.float_value();
             return Number::Float(f1 + f2);
         }
 
-        match self {
-            Number::Int(num1) => match other {
-                Number::Int(num2) => Number::Int(num1 + num2),
-                Number::Fraction(upper, lower) => Number::Fraction(upper + num1 * lower, lower)
-                    .reduce()
-                    .unwrap(),
-                _ => unreachable!(),
-            },
-            Number::Fraction(upper, lower) => match other {
-                Number::Int(num2) => Number::Fraction(upper + num2 * lower, lower)
-                    .reduce()
-                    .unwrap(),
-                Number::Fraction(other_upper, other_lower) => {
-                    let lower_lcm = Number::lcm(lower, other_lower);
-                    let self_factor = lower_lcm / lower;
-                    let other_factor = lower_lcm / other_lower;
-                    let res_upper = upper * self_factor + other_upper * other_factor;
-                    Number::Fraction(res_upper, lower_lcm).reduce().unwrap()
+        if self.is_integer() && other.is_integer() {
+            if let (Number::Int(num1), Number::Int(num2)) = (&self, &other) {
+                if let Some(res) = num1.checked_add(*num2) {
+                    return Number::Int(res);
                 }
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
+            }
+            return Number::from(self.big_int_value() + other.big_int_value());
         }
+
+        Number::from(self.rational_value() + other.rational_value())
     }
 }
 
 impl Sub for Number {
     type Output = Number;
     fn sub(self, other: Number) -> Number {
-        if self == Number::NotANumber || other == Number::NotANumber {
+        if matches!(
+            (&self, &other),
+            (Number::NotANumber, _) | (_, Number::NotANumber)
+        ) {
             return Number::NotANumber;
         }
 
-        if let (Number::Float(_), _) | (_, Number::Float(_)) = (self, other) {
+        if self.is_complex() || other.is_complex() {
+            return Number::from(self.complex_value() - other.complex_value());
+        }
+
+        if Number::is_decimal_operating(&self, &other) {
+            let result = self.decimal_value() - other.decimal_value();
+            return Number::Decimal(decimal::round(result));
+        }
+
+        if Number::is_float_operating(&self, &other) {
             // convert num1 and num2 to float type
             let f1 = self.float_value();
             let f2 = other.float_value();
             return Number::Float(f1 - f2);
         }
 
-        match self {
-            Number::Int(num1) => match other {
-                Number::Int(num2) => Number::Int(num1 - num2),
-                Number::Fraction(upper, lower) => Number::Fraction(num1 * lower - upper, lower)
-                    .reduce()
-                    .unwrap(),
-                _ => unreachable!(),
-            },
-            Number::Fraction(upper, lower) => match other {
-                Number::Int(num2) => Number::Fraction(upper - num2 * lower, lower)
-                    .reduce()
-                    .unwrap(),
-                Number::Fraction(other_upper, other_lower) => {
-                    let lower_lcm = Number::lcm(lower, other_lower);
-                    let self_factor = lower_lcm / lower;
-                    let other_factor = lower_lcm / other_lower;
-                    let res_upper = upper * self_factor - other_upper * other_factor;
-                    Number::Fraction(res_upper, lower_lcm).reduce().unwrap()
+        if self.is_integer() && other.is_integer() {
+            if let (Number::Int(num1), Number::Int(num2)) = (&self, &other) {
+                if let Some(res) = num1.checked_sub(*num2) {
+                    return Number::Int(res);
                 }
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
+            }
+            return Number::from(self.big_int_value() - other.big_int_value());
         }
+
+        Number::from(self.rational_value() - other.rational_value())
     }
 }
 
 impl Mul for Number {
     type Output = Number;
     fn mul(self, other: Number) -> Number {
-        if self == Number::NotANumber || other == Number::NotANumber {
+        if matches!(
+            (&self, &other),
+            (Number::NotANumber, _) | (_, Number::NotANumber)
+        ) {
             return Number::NotANumber;
         }
 
-        if let (Number::Float(_), _) | (_, Number::Float(_)) = (self, other) {
+        if self.is_complex() || other.is_complex() {
+            return Number::from(self.complex_value() * other.complex_value());
+        }
+
+        if Number::is_decimal_operating(&self, &other) {
+            let result = self.decimal_value() * other.decimal_value();
+            return Number::Decimal(decimal::round(result));
+        }
+
+        if Number::is_float_operating(&self, &other) {
             // convert num1 and num2 to float type
             let f1 = self.float_value();
             let f2 = other.float_value();
             return Number::Float(f1 * f2);
         }
 
-        match self {
-            Number::Int(num1) => match other {
-                Number::Int(num2) => Number::Int(num1 * num2),
-                Number::Fraction(upper, lower) => {
-                    Number::Fraction(num1 * upper, lower).reduce().unwrap()
+        if self.is_integer() && other.is_integer() {
+            if let (Number::Int(num1), Number::Int(num2)) = (&self, &other) {
+                if let Some(res) = num1.checked_mul(*num2) {
+                    return Number::Int(res);
                 }
-                _ => unreachable!(),
-            },
-            Number::Fraction(upper, lower) => match other {
-                Number::Int(num2) => Number::Fraction(upper * num2, lower).reduce().unwrap(),
-                Number::Fraction(other_upper, other_lower) => {
-                    let muled_upper = upper * other_upper;
-                    let muled_lower = lower * other_lower;
-                    Number::Fraction(muled_upper, muled_lower).reduce().unwrap()
-                }
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
+            }
+            return Number::from(self.big_int_value() * other.big_int_value());
         }
+
+        Number::from(self.rational_value() * other.rational_value())
     }
 }
 
 impl Div for Number {
     type Output = Number;
     fn div(self, other: Number) -> Number {
-        if self == Number::NotANumber || other == Number::NotANumber {
+        if matches!(
+            (&self, &other),
+            (Number::NotANumber, _) | (_, Number::NotANumber)
+        ) {
             return Number::NotANumber;
         }
 
+        if self.is_complex() || other.is_complex() {
+            return Number::from(self.complex_value() / other.complex_value());
+        }
+
+        if Number::is_decimal_operating(&self, &other) {
+            if other.is_zero() {
+                print_line__("The dividend should not to be ZERO!");
+                return Number::NotANumber;
+            }
+            let result = decimal::div(&self.decimal_value(), &other.decimal_value());
+            return Number::Decimal(result);
+        }
+
         // when either `self` or `other` is float
-        if let (Number::Float(_), _) | (_, Number::Float(_)) = (self, other) {
+        if Number::is_float_operating(&self, &other) {
             // convert num1 and num2 to float type
             let f1 = self.float_value();
             let f2 = other.float_value();
@@ -313,25 +645,34 @@ impl Div for Number {
             return Number::Float(f1 / f2);
         }
 
-        match self {
-            Number::Int(num1) => match other {
-                Number::Int(num2) => Number::Int(num1 / num2),
-                Number::Fraction(upper, lower) => {
-                    Number::Fraction(num1 * lower, upper).reduce().unwrap()
+        if other.is_zero() {
+            print_line__("The dividend should not to be ZERO!");
+            return Number::NotANumber;
+        }
+
+        // the result type of `Int / Int` depends on the division mode
+        if self.is_integer() && other.is_integer() {
+            match unsafe { ENV_OPTION.division_mode } {
+                DivisionMode::Fraction => {}
+                DivisionMode::Float if decimal::is_enabled() => {
+                    let result = decimal::div(&self.decimal_value(), &other.decimal_value());
+                    return Number::Decimal(result);
                 }
-                _ => unreachable!(),
-            },
-            Number::Fraction(upper, lower) => match other {
-                Number::Int(num2) => Number::Fraction(upper, lower * num2).reduce().unwrap(),
-                Number::Fraction(other_upper, other_lower) => {
-                    Number::Fraction(upper * other_lower, lower * other_upper)
-                        .reduce()
-                        .unwrap()
+                DivisionMode::Float => {
+                    return Number::Float(self.float_value() / other.float_value());
                 }
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
+                DivisionMode::Int => {
+                    if let (Number::Int(num1), Number::Int(num2)) = (&self, &other) {
+                        if let Some(res) = num1.checked_div(*num2) {
+                            return Number::Int(res);
+                        }
+                    }
+                    return Number::from(self.big_int_value() / other.big_int_value());
+                }
+            }
         }
+
+        Number::from(self.rational_value() / other.rational_value())
     }
 }
 
@@ -343,29 +684,49 @@ impl PartialEq for Number {
             return false;
         }
 
-        if let (Number::Float(_), _) | (_, Number::Float(_)) = (self, other) {
+        if self.is_complex() || other.is_complex() {
+            let (c1, c2) = (self.complex_value(), other.complex_value());
+            return Number::float_cmp(c1.re, c2.re) && Number::float_cmp(c1.im, c2.im);
+        }
+
+        if Number::is_decimal_operating(self, other) {
+            return self.decimal_value() == other.decimal_value();
+        }
+
+        if Number::is_float_operating(self, other) {
             let f1 = self.float_value();
             let f2 = other.float_value();
             return Number::float_cmp(f1, f2);
         }
 
-        match self {
-            Number::Int(num1) => match other {
-                Number::Int(num2) => *num1 == *num2,
-                Number::Fraction(_, _) => *num1 as f64 == other.float_value(),
-                _ => unreachable!(),
-            },
-            Number::Fraction(_, _) => match other {
-                Number::Int(num2) => self.int_value() == *num2,
-                Number::Fraction(_, _) => {
-                    Number::float_cmp(self.float_value(), other.float_value())
-                }
-                _ => unreachable!(),
-            },
-            _ => unreachable!(),
+        if let (Number::Int(num1), Number::Int(num2)) = (self, other) {
+            return num1 == num2;
         }
+        self.rational_value() == other.rational_value()
     }
-    fn ne(&self, other: &Self) -> bool {
-        !(self == other)
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if let (Number::NotANumber, _) | (_, Number::NotANumber) = (self, other) {
+            return None;
+        }
+        // complex numbers are not ordered
+        if self.is_complex() || other.is_complex() {
+            return None;
+        }
+
+        if Number::is_decimal_operating(self, other) {
+            return self.decimal_value().partial_cmp(&other.decimal_value());
+        }
+
+        if Number::is_float_operating(self, other) {
+            return self.float_value().partial_cmp(&other.float_value());
+        }
+
+        if let (Number::Int(num1), Number::Int(num2)) = (self, other) {
+            return num1.partial_cmp(num2);
+        }
+        self.rational_value().partial_cmp(&other.rational_value())
     }
 }
@@ -0,0 +1,202 @@
+// the number formatter used by the output, `string()` and `format(num, spec)`,
+// the format spec is `[,][.digits][type]`:
+// ".2f" -> fixed decimals, 3.14159 -> 3.14
+// ".3s" -> significant figures, 1234.5 -> 1230
+// ".2e" -> scientific notation, 1234.5 -> 1.23e3
+// ".2en" -> engineering notation, 12345 -> 12.35e3
+// "," -> thousands separators, 1234567 -> 1,234,567
+
+use bigdecimal::{BigDecimal, RoundingMode};
+use num_traits::Zero;
+
+use super::number::Number;
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum Notation {
+    Default,
+    Fixed(usize),
+    Significant(usize),
+    Scientific(usize),
+    Engineering(usize),
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub struct NumberFormat {
+    pub notation: Notation,
+    pub grouping: bool,
+}
+
+pub const DEFAULT_FORMAT: NumberFormat = NumberFormat {
+    notation: Notation::Default,
+    grouping: false,
+};
+// the digits used when the spec has no `.digits`
+const DEFAULT_DIGITS: usize = 6;
+
+impl NumberFormat {
+    pub fn parse(spec: &str) -> Option<NumberFormat> {
+        let (grouping, rest) = match spec.strip_prefix(',') {
+            Some(rest) => (true, rest),
+            None => (false, spec),
+        };
+        let (digits, type_name) = match rest.strip_prefix('.') {
+            Some(rest) => {
+                let end = rest
+                    .find(|ch: char| !ch.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                (Some(rest[..end].parse::<usize>().ok()?), &rest[end..])
+            }
+            None => (None, rest),
+        };
+
+        let digit_count = digits.unwrap_or(DEFAULT_DIGITS);
+        let notation = match type_name {
+            "" if digits.is_none() => Notation::Default,
+            "" | "f" => Notation::Fixed(digit_count),
+            "s" if digit_count > 0 => Notation::Significant(digit_count),
+            "e" => Notation::Scientific(digit_count),
+            "en" => Notation::Engineering(digit_count),
+            _ => return None,
+        };
+        Some(NumberFormat { notation, grouping })
+    }
+
+    pub fn format(&self, num: &Number) -> String {
+        let result = match num {
+            Number::NotANumber => return String::from("Not a Number"),
+            Number::Complex(c) => {
+                let re = self.format(&Number::Float(c.re));
+                let im = self.format(&Number::Float(c.im.abs()));
+                let sign = if c.im < 0.0 { '-' } else { '+' };
+                return format!("({} {} {}i)", re, sign, im);
+            }
+            Number::Float(f) if !f.is_finite() => f.to_string(),
+            _ => match self.notation {
+                Notation::Default => default_string(num),
+                Notation::Fixed(digits) => fixed(&num.decimal_value(), digits),
+                Notation::Significant(digits) => significant(&num.decimal_value(), digits),
+                Notation::Scientific(digits) => scientific(&num.decimal_value(), digits, 1),
+                Notation::Engineering(digits) => scientific(&num.decimal_value(), digits, 3),
+            },
+        };
+
+        if self.grouping {
+            group_digits(&result)
+        } else {
+            result
+        }
+    }
+}
+
+fn default_string(num: &Number) -> String {
+    match num {
+        Number::Int(num) => num.to_string(),
+        Number::BigInt(num) => num.to_string(),
+        Number::Float(num) => {
+            // optimize float number output
+            // example:
+            // 1.0000000000 -> 1
+            if *num == num.floor() {
+                num.to_string()
+            } else {
+                format!("{:.10}", num)
+            }
+        }
+        Number::Fraction(ratio) => format!("({} / {})", ratio.numer(), ratio.denom()),
+        Number::Decimal(d) => d.normalized().to_plain_string(),
+        Number::Complex(_) | Number::NotANumber => unreachable!(),
+    }
+}
+
+fn plain_string(value: &BigDecimal) -> String {
+    // the negative scale is displayed as the exponent by default
+    if value.fractional_digit_count() < 0 {
+        value.with_scale(0).to_plain_string()
+    } else {
+        value.to_plain_string()
+    }
+}
+// the exponent of the most significant digit,
+// example: 1234 -> 3, 0.05 -> -2
+fn exponent(value: &BigDecimal) -> i64 {
+    let (digits, scale) = value.as_bigint_and_exponent();
+    digits.magnitude().to_string().len() as i64 - 1 - scale
+}
+
+fn fixed(value: &BigDecimal, digits: usize) -> String {
+    plain_string(&value.with_scale_round(digits as i64, RoundingMode::HalfUp))
+}
+fn significant(value: &BigDecimal, digits: usize) -> String {
+    if value.is_zero() {
+        return fixed(value, digits - 1);
+    }
+    let exp = exponent(value);
+    let mut rounded = value.with_scale_round(digits as i64 - 1 - exp, RoundingMode::HalfUp);
+    if exponent(&rounded) > exp {
+        // carried to the next digit, example: 9.99 -> 10.0
+        rounded = value.with_scale_round(digits as i64 - 2 - exp, RoundingMode::HalfUp);
+    }
+    plain_string(&rounded)
+}
+// the exponent is the multiple of `step`,
+// `1` for scientific notation and `3` for engineering notation.
+fn scientific(value: &BigDecimal, digits: usize, step: i64) -> String {
+    if value.is_zero() {
+        return format!("{}e0", fixed(value, digits));
+    }
+    let (int_digits, scale) = value.as_bigint_and_exponent();
+    let limit = BigDecimal::from(10_i64.pow(step as u32));
+
+    let mut exp = exponent(value).div_euclid(step) * step;
+    loop {
+        // value / 10 ^ exp
+        let mantissa = BigDecimal::new(int_digits.clone(), scale + exp)
+            .with_scale_round(digits as i64, RoundingMode::HalfUp);
+        if mantissa.abs() >= limit {
+            exp += step;
+            continue;
+        }
+        return format!("{}e{}", plain_string(&mantissa), exp);
+    }
+}
+
+// inserts thousands separators into the integer parts
+fn group_digits(num_str: &str) -> String {
+    let chars = num_str.chars().collect::<Vec<char>>();
+    let mut result = String::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        if !chars[index].is_ascii_digit() {
+            result.push(chars[index]);
+            index += 1;
+            continue;
+        }
+        let start = index;
+        while index < chars.len() && chars[index].is_ascii_digit() {
+            index += 1;
+        }
+
+        // the fractional part and the exponent are not grouped
+        let is_integer_part = match start {
+            0 => true,
+            1 => chars[0] != '.' && chars[0] != 'e',
+            _ => {
+                let (prev, prev2) = (chars[start - 1], chars[start - 2]);
+                prev != '.' && prev != 'e' && !(prev == '-' && prev2 == 'e')
+            }
+        };
+        let digits = &chars[start..index];
+        if !is_integer_part {
+            result.extend(digits);
+            continue;
+        }
+        let groups = digits
+            .rchunks(3)
+            .rev()
+            .map(|group| group.iter().collect::<String>())
+            .collect::<Vec<String>>();
+        result.push_str(&groups.join(","));
+    }
+    result
+}
@@ -22,6 +22,7 @@ use super::utils::getter::getter;
 pub struct Class {
     properties: Vec<Property>,
     pub completer: Option<Completer>,
+    pub parent: Option<Rc<Class>>,
 
     method_storage: DataStoragePattern,
     method_list: Option<Vec<(String, Function)>>,
@@ -86,13 +87,100 @@ impl Class {
         return Class {
             properties,
             completer,
+            parent: None,
             method_storage,
             method_list,
             method_map,
         };
     }
 
-    pub fn get_method(&self, target_method: &str) -> Result<Function, ()> {
+    // the properties of parent class are placed before
+    // the properties of current class,
+    // the redeclared property overrides the type.
+    pub fn inherit(
+        parent: Rc<Class>,
+        properties: Vec<Property>,
+        methods: Vec<(String, Function)>,
+    ) -> Self {
+        let mut all_properties = parent.properties.clone();
+        for prop in properties {
+            match all_properties
+                .iter_mut()
+                .find(|Property(_, identi)| *identi == prop.1)
+            {
+                Some(parent_prop) => *parent_prop = prop,
+                None => all_properties.push(prop),
+            }
+        }
+
+        let mut class = Class::new(all_properties, methods);
+        if let Some(completer) = &mut class.completer {
+            for method_name in parent.method_names() {
+                completer.insert(&method_name);
+            }
+        }
+        class.parent = Some(parent);
+        class
+    }
+
+    fn own_method_names(&self) -> Vec<String> {
+        match self.method_storage {
+            DataStoragePattern::List => self
+                .method_list
+                .as_ref()
+                .unwrap()
+                .iter()
+                .map(|(k, _)| k.clone())
+                .collect(),
+            DataStoragePattern::Map => self.method_map.as_ref().unwrap().keys().cloned().collect(),
+        }
+    }
+    // the methods defined in this class, without the inherited ones
+    pub fn own_methods(&self) -> Vec<&Function> {
+        match self.method_storage {
+            DataStoragePattern::List => self
+                .method_list
+                .as_ref()
+                .unwrap()
+                .iter()
+                .map(|(_, method)| method)
+                .collect(),
+            DataStoragePattern::Map => self.method_map.as_ref().unwrap().values().collect(),
+        }
+    }
+    // the method names along the inheritance chain
+    pub fn method_names(&self) -> Vec<String> {
+        let mut names = self.own_method_names();
+        if let Some(parent) = &self.parent {
+            for name in parent.method_names() {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    // checks whether `self` is `target` or inherits from `target`
+    pub fn is_subclass_of(&self, target: &Class) -> bool {
+        if std::ptr::eq(self, target) {
+            return true;
+        }
+        match &self.parent {
+            Some(parent) => parent.is_subclass_of(target),
+            None => false,
+        }
+    }
+
+    pub fn property_names(&self) -> Vec<String> {
+        self.properties
+            .iter()
+            .map(|Property(_, identi)| identi.clone())
+            .collect()
+    }
+
+    // looks up the method through the inheritance chain
+    pub fn find_method(&self, target_method: &str) -> Option<Function> {
         let result_target_method = getter::<Function>(
             self.method_storage,
             target_method,
@@ -100,9 +188,17 @@ impl Class {
             &self.method_map,
         );
 
-        match result_target_method {
-            Ok(target_method) => Ok(target_method),
-            Err(()) => Err(reference_error(ReferenceType::Property, target_method)?),
+        match (result_target_method, &self.parent) {
+            (Ok(target_method), _) => Some(target_method),
+            (Err(()), Some(parent)) => parent.find_method(target_method),
+            (Err(()), None) => None,
+        }
+    }
+
+    pub fn get_method(&self, target_method: &str) -> Result<Function, ()> {
+        match self.find_method(target_method) {
+            Some(target_method) => Ok(target_method),
+            None => Err(reference_error(ReferenceType::Property, target_method)?),
         }
     }
 
@@ -172,32 +268,15 @@ impl Class {
             String::from(Class::METHOD_DISP_STR)
         };
 
-        match cls.method_storage {
-            DataStoragePattern::List => {
-                let list = cls.method_list.as_ref().unwrap();
-                for method in list {
-                    write!(
-                        f,
-                        "{}{}: {}\r\n",
-                        display_indent(level),
-                        method.0,
-                        class_method_disp
-                    )?;
-                }
-            }
-            DataStoragePattern::Map => {
-                let map = cls.method_map.as_ref().unwrap();
-
-                for (key, _) in map {
-                    write!(
-                        f,
-                        "{}{}: {}\r\n",
-                        display_indent(level),
-                        key,
-                        class_method_disp
-                    )?;
-                }
-            }
+        // the inherited methods are displayed as well
+        for method_name in cls.method_names() {
+            write!(
+                f,
+                "{}{}: {}\r\n",
+                display_indent(level),
+                method_name,
+                class_method_disp
+            )?;
         }
         return Ok(());
     }
@@ -0,0 +1,360 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Pow};
+
+use crate::public::error::{math_error, syntax_error};
+
+use super::number::Number;
+use super::value::Value;
+
+// the exponents of SI base units:
+// [m, kg, s, A, K, mol, cd]
+type Dimension = [i32; 7];
+
+const DIMENSIONLESS: Dimension = [0; 7];
+
+// (symbol, factor to the SI coherent unit, dimension, is prefixable)
+const UNIT_TABLE: [(&str, &str, Dimension, bool); 35] = [
+    // SI base units
+    ("m", "1", [1, 0, 0, 0, 0, 0, 0], true),
+    ("g", "0.001", [0, 1, 0, 0, 0, 0, 0], true),
+    ("s", "1", [0, 0, 1, 0, 0, 0, 0], true),
+    ("A", "1", [0, 0, 0, 1, 0, 0, 0], true),
+    ("K", "1", [0, 0, 0, 0, 1, 0, 0], true),
+    ("mol", "1", [0, 0, 0, 0, 0, 1, 0], true),
+    ("cd", "1", [0, 0, 0, 0, 0, 0, 1], true),
+    // SI derived units
+    ("Hz", "1", [0, 0, -1, 0, 0, 0, 0], true),
+    ("N", "1", [1, 1, -2, 0, 0, 0, 0], true),
+    ("Pa", "1", [-1, 1, -2, 0, 0, 0, 0], true),
+    ("J", "1", [2, 1, -2, 0, 0, 0, 0], true),
+    ("W", "1", [2, 1, -3, 0, 0, 0, 0], true),
+    ("C", "1", [0, 0, 1, 1, 0, 0, 0], true),
+    ("V", "1", [2, 1, -3, -1, 0, 0, 0], true),
+    ("F", "1", [-2, -1, 4, 2, 0, 0, 0], true),
+    ("ohm", "1", [2, 1, -3, -2, 0, 0, 0], true),
+    ("S", "1", [-2, -1, 3, 2, 0, 0, 0], true),
+    ("Wb", "1", [2, 1, -2, -1, 0, 0, 0], true),
+    ("T", "1", [0, 1, -2, -1, 0, 0, 0], true),
+    ("H", "1", [2, 1, -2, -2, 0, 0, 0], true),
+    // non-SI units accepted for use with SI
+    ("L", "0.001", [3, 0, 0, 0, 0, 0, 0], true),
+    ("t", "1000", [0, 1, 0, 0, 0, 0, 0], false),
+    ("min", "60", [0, 0, 1, 0, 0, 0, 0], false),
+    ("h", "3600", [0, 0, 1, 0, 0, 0, 0], false),
+    ("day", "86400", [0, 0, 1, 0, 0, 0, 0], false),
+    ("Wh", "3600", [2, 1, -2, 0, 0, 0, 0], true),
+    (
+        "eV",
+        "0.0000000000000000001602176634",
+        [2, 1, -2, 0, 0, 0, 0],
+        true,
+    ),
+    // imperial units
+    ("in", "0.0254", [1, 0, 0, 0, 0, 0, 0], false),
+    ("ft", "0.3048", [1, 0, 0, 0, 0, 0, 0], false),
+    ("yd", "0.9144", [1, 0, 0, 0, 0, 0, 0], false),
+    ("mi", "1609.344", [1, 0, 0, 0, 0, 0, 0], false),
+    ("lb", "0.45359237", [0, 1, 0, 0, 0, 0, 0], false),
+    ("oz", "0.028349523125", [0, 1, 0, 0, 0, 0, 0], false),
+    ("gal", "0.003785411784", [3, 0, 0, 0, 0, 0, 0], false),
+    ("mph", "0.44704", [1, 0, -1, 0, 0, 0, 0], false),
+];
+
+// (prefix, exponent of 10)
+const PREFIX_TABLE: [(&str, i32); 20] = [
+    ("Y", 24),
+    ("Z", 21),
+    ("E", 18),
+    ("P", 15),
+    ("T", 12),
+    ("G", 9),
+    ("M", 6),
+    ("k", 3),
+    ("h", 2),
+    ("da", 1),
+    ("d", -1),
+    ("c", -2),
+    ("m", -3),
+    ("u", -6),
+    ("n", -9),
+    ("p", -12),
+    ("f", -15),
+    ("a", -18),
+    ("z", -21),
+    ("y", -24),
+];
+
+// "0.001" -> 1 / 1000
+fn parse_factor(factor: &str) -> BigRational {
+    let (int_part, frac_part) = factor.split_once('.').unwrap_or((factor, ""));
+    let digits = format!("{}{}", int_part, frac_part);
+    let numer = digits.parse::<BigInt>().unwrap();
+    let denom = Pow::pow(BigInt::from(10), frac_part.len() as u32);
+    BigRational::new(numer, denom)
+}
+
+#[derive(PartialEq, Clone)]
+pub struct Unit {
+    // unit symbols with exponents,
+    // example: km/h -> [("km", 1), ("h", -1)]
+    symbols: Vec<(String, i32)>,
+    // the ratio to the SI coherent unit,
+    // example: km -> 1000
+    factor: BigRational,
+    dimension: Dimension,
+}
+
+impl Unit {
+    fn symbol_unit(symbol: &str) -> Option<Unit> {
+        fn from_table(symbol: &str) -> Option<(BigRational, Dimension, bool)> {
+            for (name, factor, dimension, is_prefixable) in UNIT_TABLE {
+                if name == symbol {
+                    return Some((parse_factor(factor), dimension, is_prefixable));
+                }
+            }
+            None
+        }
+
+        let (factor, dimension) = match from_table(symbol) {
+            Some((factor, dimension, _)) => (factor, dimension),
+            None => {
+                // with SI prefix, example: km, mA, GHz
+                let (factor, dimension) = PREFIX_TABLE.iter().find_map(|(prefix, exp)| {
+                    let base_symbol = symbol.strip_prefix(prefix)?;
+                    let (base_factor, dimension, is_prefixable) = from_table(base_symbol)?;
+                    if !is_prefixable {
+                        return None;
+                    }
+                    let scale = Pow::pow(BigRational::from(BigInt::from(10)), *exp);
+                    Some((base_factor * scale, dimension))
+                })?;
+                (factor, dimension)
+            }
+        };
+        Some(Unit {
+            symbols: vec![(symbol.to_string(), 1)],
+            factor,
+            dimension,
+        })
+    }
+
+    // parses unit expressions,
+    // example: "km", "km/h", "m/s^2", "kg*m^2/s^2"
+    pub fn parse(source: &str) -> Option<Unit> {
+        let mut unit = Unit::dimensionless();
+        let mut is_divided = false;
+        let mut term = String::new();
+
+        for ch in source.chars().chain(std::iter::once('*')) {
+            if ch.is_whitespace() {
+                continue;
+            }
+            if ch != '*' && ch != '/' {
+                term.push(ch);
+                continue;
+            }
+
+            let (symbol, exp) = match term.split_once('^') {
+                Some((symbol, exp)) => (symbol, exp.parse::<i32>().ok()?),
+                None => (term.as_str(), 1),
+            };
+            let exp = if is_divided { -exp } else { exp };
+            // example: "1/s"
+            if symbol != "1" {
+                unit = unit.mul(&Unit::symbol_unit(symbol)?.pow(exp));
+            }
+
+            term.clear();
+            is_divided = ch == '/';
+        }
+        Some(unit)
+    }
+
+    fn dimensionless() -> Unit {
+        Unit {
+            symbols: vec![],
+            factor: BigRational::one(),
+            dimension: DIMENSIONLESS,
+        }
+    }
+    fn is_dimensionless(&self) -> bool {
+        self.dimension == DIMENSIONLESS
+    }
+
+    fn mul(&self, other: &Unit) -> Unit {
+        let mut symbols = self.symbols.clone();
+        for (symbol, exp) in &other.symbols {
+            match symbols.iter_mut().find(|(s, _)| s == symbol) {
+                Some((_, current_exp)) => *current_exp += exp,
+                None => symbols.push((symbol.clone(), *exp)),
+            }
+        }
+        symbols.retain(|(_, exp)| *exp != 0);
+
+        let mut dimension = self.dimension;
+        for (index, exp) in other.dimension.iter().enumerate() {
+            dimension[index] += exp;
+        }
+        Unit {
+            symbols,
+            factor: &self.factor * &other.factor,
+            dimension,
+        }
+    }
+    fn pow(&self, exp: i32) -> Unit {
+        Unit {
+            symbols: self
+                .symbols
+                .iter()
+                .map(|(symbol, current_exp)| (symbol.clone(), current_exp * exp))
+                .collect(),
+            factor: Pow::pow(&self.factor, exp),
+            dimension: self.dimension.map(|current_exp| current_exp * exp),
+        }
+    }
+    fn inverse(&self) -> Unit {
+        self.pow(-1)
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn join<'a>(symbols: impl Iterator<Item = (&'a String, i32)>) -> String {
+            symbols
+                .map(|(symbol, exp)| {
+                    if exp == 1 {
+                        symbol.clone()
+                    } else {
+                        format!("{}^{}", symbol, exp)
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join("*")
+        }
+
+        let numerator = join(
+            self.symbols
+                .iter()
+                .filter(|(_, exp)| *exp > 0)
+                .map(|(symbol, exp)| (symbol, *exp)),
+        );
+        let denominator = join(
+            self.symbols
+                .iter()
+                .filter(|(_, exp)| *exp < 0)
+                .map(|(symbol, exp)| (symbol, -exp)),
+        );
+
+        match (numerator.is_empty(), denominator.is_empty()) {
+            (_, true) => write!(f, "{}", numerator),
+            (true, false) => write!(f, "1/{}", denominator),
+            (false, false) => write!(f, "{}/{}", numerator, denominator),
+        }
+    }
+}
+
+// --- --- --- --- --- ---
+
+#[derive(PartialEq, Clone)]
+pub struct Quantity {
+    pub value: Number,
+    pub unit: Unit,
+}
+
+impl Quantity {
+    pub fn new(value: Number, unit: Unit) -> Quantity {
+        Quantity { value, unit }
+    }
+
+    // the dimensionless quantity is converted to number,
+    // example: 1 km / 1 m -> 1000
+    pub fn into_value(self) -> Value {
+        if self.unit.is_dimensionless() {
+            let factor = Number::from(self.unit.factor);
+            Value::Number(self.value * factor)
+        } else {
+            Value::Quantity(self)
+        }
+    }
+
+    // converts to the target unit with the same dimension
+    pub fn convert(&self, target: &Unit) -> Result<Quantity, ()> {
+        // the plain number has no unit symbol to show
+        fn unit_name(unit: &Unit) -> String {
+            if unit.symbols.is_empty() {
+                String::from("`1` (dimensionless)")
+            } else {
+                format!("`{}`", unit)
+            }
+        }
+
+        if self.unit.dimension != target.dimension {
+            let msg = format!(
+                "can not convert {} to {}",
+                unit_name(&self.unit),
+                unit_name(target)
+            );
+            return Err(math_error(&msg)?);
+        }
+        let ratio = Number::from(&self.unit.factor / &target.factor);
+        Ok(Quantity::new(self.value.clone() * ratio, target.clone()))
+    }
+
+    pub fn add(self, other: Quantity) -> Result<Quantity, ()> {
+        let converted = other.convert(&self.unit)?;
+        Ok(Quantity::new(self.value + converted.value, self.unit))
+    }
+    pub fn sub(self, other: Quantity) -> Result<Quantity, ()> {
+        let converted = other.convert(&self.unit)?;
+        Ok(Quantity::new(self.value - converted.value, self.unit))
+    }
+    pub fn mul(self, other: Quantity) -> Quantity {
+        let unit = self.unit.mul(&other.unit);
+        Quantity::new(self.value * other.value, unit)
+    }
+    pub fn div(self, other: Quantity) -> Quantity {
+        let unit = self.unit.mul(&other.unit.inverse());
+        Quantity::new(self.value / other.value, unit)
+    }
+    pub fn pow(self, exp: Number) -> Result<Quantity, ()> {
+        let Number::Int(exp_i) = exp else {
+            return Err(math_error("the exponent of quantity should be integer")?);
+        };
+        let Ok(exp_i32) = i32::try_from(exp_i) else {
+            return Err(math_error("the exponent of quantity is too large")?);
+        };
+        let unit = self.unit.pow(exp_i32);
+        Ok(Quantity::new(self.value.pow(exp), unit))
+    }
+    pub fn compare(&self, other: &Quantity) -> Result<Option<Ordering>, ()> {
+        let converted = other.convert(&self.unit)?;
+        Ok(self.value.partial_cmp(&converted.value))
+    }
+}
+
+impl From<Number> for Quantity {
+    fn from(value: Number) -> Self {
+        Quantity::new(value, Unit::dimensionless())
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.value, self.unit)
+    }
+}
+
+// is used by `to(input, unit)`
+pub fn unit_parse(source: &str) -> Result<Unit, ()> {
+    match Unit::parse(source) {
+        Some(unit) => Ok(unit),
+        None => {
+            let msg = format!("invalid unit `{}`", source);
+            Err(syntax_error(&msg)?)
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+
+use crate::public::error::math_error;
+
+use super::array::ArrayLiteral;
+use super::number::Number;
+use super::value::Value;
+
+// the lazily generated number sequence:
+// `start..end`, `start..=end` and `start..end..step`
+#[derive(PartialEq, Clone)]
+pub struct Range {
+    pub start: Number,
+    pub end: Number,
+    pub step: Number,
+    pub is_inclusive: bool,
+}
+
+pub struct RangeIter {
+    range: Range,
+    index: i64,
+}
+
+impl Range {
+    pub fn new(start: Number, end: Number, is_inclusive: bool) -> Result<Range, ()> {
+        if start.is_complex() || end.is_complex() {
+            return Err(math_error("complex numbers can not be the bound of range")?);
+        }
+        Ok(Range {
+            start,
+            end,
+            step: Number::Int(1),
+            is_inclusive,
+        })
+    }
+
+    pub fn with_step(&self, step: Number) -> Result<Range, ()> {
+        if step.is_complex() {
+            return Err(math_error("complex numbers can not be the step of range")?);
+        }
+        if step.is_zero() {
+            return Err(math_error("the step of range should not be ZERO")?);
+        }
+        Ok(Range {
+            step,
+            ..self.clone()
+        })
+    }
+
+    pub fn iter(&self) -> RangeIter {
+        RangeIter {
+            range: self.clone(),
+            index: 0,
+        }
+    }
+    // estimates the count with the floored division, then corrects it
+    // with the bound check of the iterator, so that the float error
+    // can not make `len` and the iteration disagree.
+    pub fn len(&self) -> usize {
+        let quotient = (self.end.clone() - self.start.clone()).floor_div(self.step.clone());
+        let mut count = quotient.int_value().max(-1).saturating_add(1);
+        while count > 0 && !self.contains_index(count - 1) {
+            count -= 1;
+        }
+        while count < i64::MAX && self.contains_index(count) {
+            count += 1;
+        }
+        count as usize
+    }
+    pub fn to_array(&self) -> ArrayLiteral {
+        self.iter().map(Value::Number).collect()
+    }
+
+    // computes from the start every time
+    // to avoid the accumulated float error
+    fn nth(&self, index: i64) -> Number {
+        self.start.clone() + self.step.clone() * Number::Int(index)
+    }
+    fn contains_index(&self, index: i64) -> bool {
+        let current = self.nth(index);
+        match (self.step > Number::Int(0), self.is_inclusive) {
+            (true, false) => current < self.end,
+            (true, true) => current <= self.end,
+            (false, false) => current > self.end,
+            (false, true) => current >= self.end,
+        }
+    }
+}
+
+impl Iterator for RangeIter {
+    type Item = Number;
+
+    fn next(&mut self) -> Option<Number> {
+        if !self.range.contains_index(self.index) {
+            return None;
+        }
+        let current = self.range.nth(self.index);
+        self.index += 1;
+        Some(current)
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let range_symbol = if self.is_inclusive { "..=" } else { ".." };
+        write!(f, "{}{}{}", self.start, range_symbol, self.end)?;
+        if self.step != Number::Int(1) {
+            write!(f, "..{}", self.step)?;
+        }
+        Ok(())
+    }
+}
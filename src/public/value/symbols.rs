@@ -9,7 +9,11 @@ pub enum Symbols {
     Minus,
     Multiply,
     Divide,
+    Modulo,
+    FloorDivide,
     Power, // high priority
+    // prefix `-` of the non-literal operand
+    Negate,
 
     Not,
     LessThan,
@@ -19,12 +23,36 @@ pub enum Symbols {
     CompareEqual,
     NotEqual,
 
+    // logical symbols
+    LogicalNot,
+    And,
+    Or,
+
+    // bitwise symbols
+    BitAnd,
+    BitOr,
+    BitXor,
+    BitNot,
+    LeftShift,
+    RightShift,
+
+    // range symbols
+    Range,
+    RangeInclusive,
+
     Equal,
     PlusEqual,
     MinusEqual,
     MultiplyEqual,
     DivideEqual,
+    ModuloEqual,
+    FloorDivideEqual,
     PowerEqual,
+    BitAndEqual,
+    BitOrEqual,
+    BitXorEqual,
+    LeftShiftEqual,
+    RightShiftEqual,
 
     ObjectReading,
 }
@@ -36,6 +64,9 @@ impl Symbols {
             '-' => Self::Minus,
             '*' => Self::Multiply,
             '/' => Self::Divide,
+            '%' => Self::Modulo,
+            '&' => Self::BitAnd,
+            '|' => Self::BitOr,
             '^' => Self::Power,
             '!' => Self::Not,
             '<' => Self::LessThan,
@@ -64,11 +95,19 @@ impl Symbols {
             Symbols::Minus => Symbols::MinusEqual,
             Symbols::Multiply => Symbols::MultiplyEqual,
             Symbols::Divide => Symbols::DivideEqual,
+            Symbols::Modulo => Symbols::ModuloEqual,
+            Symbols::FloorDivide => Symbols::FloorDivideEqual,
             Symbols::Power => Symbols::PowerEqual,
+            Symbols::BitAnd => Symbols::BitAndEqual,
+            Symbols::BitOr => Symbols::BitOrEqual,
+            Symbols::BitXor => Symbols::BitXorEqual,
+            Symbols::LeftShift => Symbols::LeftShiftEqual,
+            Symbols::RightShift => Symbols::RightShiftEqual,
             Symbols::LessThan => Symbols::LessThanEqual,
             Symbols::MoreThan => Symbols::MoreThanEqual,
             Symbols::Not => Symbols::NotEqual,
             Symbols::Equal => Symbols::CompareEqual,
+            Symbols::Range => Symbols::RangeInclusive,
             _ => {
                 let msg = format!("invalid symbol `{}` for symbol combination", other);
                 return Err(internal_error(InternalComponent::Tokenizer, &msg)?);
@@ -82,7 +121,14 @@ impl Symbols {
             Symbols::MinusEqual => Symbols::Minus,
             Symbols::MultiplyEqual => Symbols::Multiply,
             Symbols::DivideEqual => Symbols::Divide,
+            Symbols::ModuloEqual => Symbols::Modulo,
+            Symbols::FloorDivideEqual => Symbols::FloorDivide,
             Symbols::PowerEqual => Symbols::Power,
+            Symbols::BitAndEqual => Symbols::BitAnd,
+            Symbols::BitOrEqual => Symbols::BitOr,
+            Symbols::BitXorEqual => Symbols::BitXor,
+            Symbols::LeftShiftEqual => Symbols::LeftShift,
+            Symbols::RightShiftEqual => Symbols::RightShift,
             _ => self,
         }
     }
@@ -92,20 +138,87 @@ impl Symbols {
             || symbol == Symbols::Minus
             || symbol == Symbols::Multiply
             || symbol == Symbols::Divide
+            || symbol == Symbols::Modulo
+            || symbol == Symbols::FloorDivide
             || symbol == Symbols::Power
             || symbol == Symbols::Not
             || symbol == Symbols::LessThan
             || symbol == Symbols::MoreThan
+            || symbol == Symbols::BitAnd
+            || symbol == Symbols::BitOr
+            || symbol == Symbols::BitXor
+            || symbol == Symbols::LeftShift
+            || symbol == Symbols::RightShift
+            || symbol == Symbols::Range
             || symbol == Symbols::Equal;
     }
 
+    // the text of the symbol in source code
+    pub fn source_text(&self) -> &'static str {
+        match self {
+            Symbols::Plus => "+",
+            Symbols::Minus => "-",
+            Symbols::Multiply => "*",
+            Symbols::Divide => "/",
+            Symbols::Modulo => "%",
+            Symbols::FloorDivide => "//",
+            Symbols::Power => "^",
+            Symbols::Negate => "-",
+
+            Symbols::Not => "!",
+            Symbols::LessThan => "<",
+            Symbols::MoreThan => ">",
+            Symbols::Equal => "=",
+            Symbols::LessThanEqual => "<=",
+            Symbols::MoreThanEqual => ">=",
+            Symbols::NotEqual => "!=",
+            Symbols::CompareEqual => "==",
+
+            Symbols::LogicalNot => "not",
+            Symbols::And => "&&",
+            Symbols::Or => "||",
+
+            Symbols::BitAnd => "&",
+            Symbols::BitOr => "|",
+            Symbols::BitXor => "~",
+            Symbols::BitNot => "~",
+            Symbols::LeftShift => "<<",
+            Symbols::RightShift => ">>",
+
+            Symbols::Range => "..",
+            Symbols::RangeInclusive => "..=",
+
+            Symbols::PlusEqual => "+=",
+            Symbols::MinusEqual => "-=",
+            Symbols::MultiplyEqual => "*=",
+            Symbols::DivideEqual => "/=",
+            Symbols::ModuloEqual => "%=",
+            Symbols::FloorDivideEqual => "//=",
+            Symbols::PowerEqual => "^=",
+            Symbols::BitAndEqual => "&=",
+            Symbols::BitOrEqual => "|=",
+            Symbols::BitXorEqual => "~=",
+            Symbols::LeftShiftEqual => "<<=",
+            Symbols::RightShiftEqual => ">>=",
+
+            Symbols::ObjectReading => ".",
+        }
+    }
+
     pub fn is_equal_symbol(symbol: Symbols) -> bool {
         return symbol == Symbols::Equal
             || symbol == Symbols::PlusEqual
             || symbol == Symbols::MinusEqual
             || symbol == Symbols::MultiplyEqual
             || symbol == Symbols::DivideEqual
-            || symbol == Symbols::PowerEqual;
+            || symbol == Symbols::ModuloEqual
+            || symbol == Symbols::FloorDivideEqual
+            || symbol == Symbols::PowerEqual
+            || symbol == Symbols::BitAndEqual
+            || symbol == Symbols::BitOrEqual
+            || symbol == Symbols::BitXorEqual
+            || symbol == Symbols::LeftShiftEqual
+            || symbol == Symbols::RightShiftEqual;
     }
 }
 
@@ -116,7 +229,10 @@ impl fmt::Display for Symbols {
             Symbols::Minus => write!(f, "Minus"),
             Symbols::Multiply => write!(f, "Multiply"),
             Symbols::Divide => write!(f, "Divide"),
+            Symbols::Modulo => write!(f, "Modulo"),
+            Symbols::FloorDivide => write!(f, "FloorDivide"),
             Symbols::Power => write!(f, "Power"),
+            Symbols::Negate => write!(f, "Negate"),
 
             Symbols::Not => write!(f, "Not"),
             Symbols::LessThan => write!(f, "LessThan"),
@@ -127,11 +243,32 @@ impl fmt::Display for Symbols {
             Symbols::NotEqual => write!(f, "NotEqual"),
             Symbols::CompareEqual => write!(f, "CompareEqual"),
 
+            Symbols::LogicalNot => write!(f, "LogicalNot"),
+            Symbols::And => write!(f, "And"),
+            Symbols::Or => write!(f, "Or"),
+
+            Symbols::BitAnd => write!(f, "BitAnd"),
+            Symbols::BitOr => write!(f, "BitOr"),
+            Symbols::BitXor => write!(f, "BitXor"),
+            Symbols::BitNot => write!(f, "BitNot"),
+            Symbols::LeftShift => write!(f, "LeftShift"),
+            Symbols::RightShift => write!(f, "RightShift"),
+
+            Symbols::Range => write!(f, "Range"),
+            Symbols::RangeInclusive => write!(f, "RangeInclusive"),
+
             Symbols::PlusEqual => write!(f, "PlusEqual"),
             Symbols::MinusEqual => write!(f, "MinusEqual"),
             Symbols::MultiplyEqual => write!(f, "MultiplyEqual"),
             Symbols::DivideEqual => write!(f, "DivideEqual"),
+            Symbols::ModuloEqual => write!(f, "ModuloEqual"),
+            Symbols::FloorDivideEqual => write!(f, "FloorDivideEqual"),
             Symbols::PowerEqual => write!(f, "PowerEqual"),
+            Symbols::BitAndEqual => write!(f, "BitAndEqual"),
+            Symbols::BitOrEqual => write!(f, "BitOrEqual"),
+            Symbols::BitXorEqual => write!(f, "BitXorEqual"),
+            Symbols::LeftShiftEqual => write!(f, "LeftShiftEqual"),
+            Symbols::RightShiftEqual => write!(f, "RightShiftEqual"),
 
             Symbols::ObjectReading => write!(f, "ObjectReading"),
         }
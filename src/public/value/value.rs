@@ -13,6 +13,8 @@ use super::function::{BuildInFunction, Function, UserDefinedFunction};
 use super::number::Number;
 use super::oop::class::Class;
 use super::oop::object::Object;
+use super::quantity::Quantity;
+use super::range::Range;
 
 #[derive(PartialEq, Clone, Copy)]
 pub enum ValueType {
@@ -27,9 +29,12 @@ pub enum ValueType {
     Function,
     Class,
     Object,
+
+    Quantity,
+    Range,
 }
 
-pub const VALUE_TYPE_PAIRS: [(&'static str, ValueType); 8] = [
+pub const VALUE_TYPE_PAIRS: [(&'static str, ValueType); 10] = [
     ("_", ValueType::Void),
     ("Bool", ValueType::Boolean),
     ("Numb", ValueType::Number),
@@ -38,6 +43,8 @@ pub const VALUE_TYPE_PAIRS: [(&'static str, ValueType); 8] = [
     ("LazyExpr", ValueType::LazyExpression),
     ("Func", ValueType::Function),
     ("Obj", ValueType::Object),
+    ("Qty", ValueType::Quantity),
+    ("Range", ValueType::Range),
 ];
 
 impl ValueType {
@@ -63,6 +70,8 @@ impl fmt::Display for ValueType {
             ValueType::Function => write!(f, "Function"),
             ValueType::Class => write!(f, "Class"),
             ValueType::Object => write!(f, "Object"),
+            ValueType::Quantity => write!(f, "Quantity"),
+            ValueType::Range => write!(f, "Range"),
         }
     }
 }
@@ -72,6 +81,9 @@ impl fmt::Display for ValueType {
 pub enum VoidSign {
     Continue,
     Break(Rc<Value>),
+    // returns from the function,
+    // passes through the loops and if blocks.
+    Return(Rc<Value>),
     Empty,
 }
 #[derive(PartialEq, Clone)]
@@ -91,6 +103,9 @@ pub enum Value {
     Function(Function),
     Class(Rc<Class>),
     Object(Rc<RefCell<Object>>),
+
+    Quantity(Quantity),
+    Range(Rc<Range>),
 }
 
 impl Value {
@@ -113,22 +128,14 @@ impl Value {
         };
         return Ok(num.int_value());
     }
-    pub fn get_f64(&self) -> Result<f64, ()> {
-        // expected Number typed value to call this method
-        let Value::Number(num) = self else {
-            return Err(internal_error(
-                InternalComponent::InternalFn,
-                "invalid `Value::get_f64` invocation"
-            )?)
-        };
-        return Ok(num.float_value());
-    }
     pub fn get_bool(&self) -> bool {
         match self {
             Value::Boolean(bool_val) => *bool_val,
             Value::Number(num) => *num != Number::Int(0),
+            Value::Quantity(qty) => qty.value != Number::Int(0),
             Value::String(str) => str.as_ref().borrow().len() > 0,
             Value::Array(arr) => arr.as_ref().borrow().len() > 0,
+            Value::Range(range) => range.iter().next().is_some(),
 
             Value::Void(_) => false,
             Value::LazyExpression(_) | Value::Function(_) | Value::Class(_) | Value::Object(_) => {
@@ -154,6 +161,8 @@ impl Value {
             Value::Void(_) => self.to_string(),
             Value::Boolean(bool_val) => bool_val.to_string(),
             Value::Number(num) => num.to_string(),
+            Value::Quantity(qty) => qty.to_string(),
+            Value::Range(range) => range.to_string(),
             Value::String(str) => str.borrow().clone(),
             Value::Function(func) => func.to_string(),
             Value::Array(arr) => Array::join(&arr.borrow(), ", "),
@@ -175,6 +184,9 @@ impl Value {
             // can be directly cloned.
             Value::Boolean(_)
             | Value::Number(_)
+            | Value::Quantity(_)
+            // Range is immutable
+            | Value::Range(_)
             // Function and Class can not be modified,
             // can just clone their Rc.
             | Value::Function(_)
@@ -218,6 +230,9 @@ impl Value {
             Value::Function(_) => ValueType::Function,
             Value::Class(_) => ValueType::Class,
             Value::Object(_) => ValueType::Object,
+
+            Value::Quantity(_) => ValueType::Quantity,
+            Value::Range(_) => ValueType::Range,
         }
     }
     pub fn check_type(&self, target_type: ValueType) -> bool {
@@ -240,7 +255,7 @@ impl fmt::Display for Value {
         match self {
             Value::Void(void_sign) => match void_sign {
                 VoidSign::Continue => write!(f, "Void(Continue)"),
-                VoidSign::Break(val) => write!(f, "Void({})", val),
+                VoidSign::Break(val) | VoidSign::Return(val) => write!(f, "Void({})", val),
                 VoidSign::Empty => write!(f, "<Void>"),
             },
 
@@ -255,7 +270,9 @@ impl fmt::Display for Value {
                         Value::Boolean(bool_val) => {
                             write!(f, "{}", bool_val.to_string().dark_yellow())
                         }
-                        Value::Number(num) => write!(f, "{}", num.to_string().yellow()),
+                        Value::Number(num) => write!(f, "{}", num.output_string().yellow()),
+                        Value::Quantity(qty) => write!(f, "{}", qty.to_string().yellow()),
+                        Value::Range(range) => write!(f, "{}", range.to_string().yellow()),
                         Value::LazyExpression(_) => write!(f, "{}", "<Lazy-Expression>".cyan()),
                         Value::Function(func) => write!(f, "{}", func.to_string().cyan()),
                         _ => unreachable!(),
@@ -263,7 +280,9 @@ impl fmt::Display for Value {
                 } else {
                     match self {
                         Value::Boolean(bool_val) => write!(f, "{}", bool_val),
-                        Value::Number(num) => write!(f, "{}", num),
+                        Value::Number(num) => write!(f, "{}", num.output_string()),
+                        Value::Quantity(qty) => write!(f, "{}", qty),
+                        Value::Range(range) => write!(f, "{}", range),
                         Value::LazyExpression(_) => write!(f, "{}", "<Lazy-Expression>"),
                         Value::Function(func) => write!(f, "{}", func),
                         _ => unreachable!(),
@@ -294,6 +313,11 @@ impl From<String> for Value {
         Value::String(Rc::new(RefCell::new(value)))
     }
 }
+impl From<Range> for Value {
+    fn from(value: Range) -> Self {
+        Value::Range(Rc::new(value))
+    }
+}
 impl From<ArrayLiteral> for Value {
     fn from(value: ArrayLiteral) -> Self {
         Value::Array(Rc::new(RefCell::new(value)))
@@ -12,7 +12,9 @@ const ALLOWED_CHAR_MAP: [bool; 95] = [
     true, // '"'
     true, // '#'
     true, // '$'
-    false, false, true, // '\''
+    true, // '%'
+    true, // '&'
+    true, // '\''
     true, // '('
     true, // ')'
     true, // '*'
@@ -27,7 +29,7 @@ const ALLOWED_CHAR_MAP: [bool; 95] = [
     true, // '<'
     true, // '='
     true, // '>'
-    false, false, true, // 'A'
+    true, false, true, // 'A'
     true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true,
     true, true, true, true, true, true, true, true, true, // 'Z'
     true, // '['
@@ -39,8 +41,9 @@ const ALLOWED_CHAR_MAP: [bool; 95] = [
     true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true,
     true, true, true, true, true, true, true, true, true, // 'z'
     true, // '{'
-    false, true, // '}'
-    false,
+    true, // '|'
+    true, // '}'
+    true, // '~'
 ];
 
 pub struct Line {
@@ -1,6 +1,8 @@
 mod token;
 
-use crate::{public::compile_time::keywords::Keyword, utils::ascii::is_identi_ascii};
+use crate::public::compile_time::keywords::Keyword;
+use crate::public::value::quantity::Unit;
+use crate::utils::ascii::is_identi_ascii;
 
 pub use token::{TextType, Token, TokenType, TokenVec};
 
@@ -42,8 +44,25 @@ pub fn tokenize(source: &str) -> TokenVec {
                 value.insert(0, '-');
             }
 
+            // radix literals: 0x1F, 0o17, 0b1010
+            let is_radix = ch == '0'
+                && matches!(
+                    chars.clone().next(),
+                    Some('x' | 'X' | 'o' | 'O' | 'b' | 'B')
+                );
+
             while let Some(ch) = chars.next() {
-                if ch.is_ascii_digit() || ch == '.' {
+                // the exponent sign, example: 1.5e-3
+                let is_exponent_sign = (ch == '+' || ch == '-')
+                    && !is_radix
+                    && value.ends_with(['e', 'E'])
+                    && chars
+                        .clone()
+                        .next()
+                        .is_some_and(|next| next.is_ascii_digit());
+
+                // letters for radix prefix, hex digits, exponent and imaginary unit suffix
+                if ch.is_ascii_alphanumeric() || ch == '.' || ch == '_' || is_exponent_sign {
                     value.push(ch);
                 } else {
                     cached_ch = ch;
@@ -72,9 +91,15 @@ pub fn tokenize(source: &str) -> TokenVec {
             if last_type == TokenType::Annotation {
                 // Type annotation
                 tokens.push(Token::new(TextType::Annotation, value));
+            } else if last_type == TokenType::Number && Unit::parse(&value).is_some() {
+                // unit of quantity literal, example: 3 km
+                tokens.push(Token::new(TextType::NumberLiteral, value));
             } else {
                 let option_keyword = Keyword::is_keyword(&value);
-                let is_keyword = option_keyword.is_some() || value.eq("true") || value.eq("false");
+                let is_keyword = option_keyword.is_some()
+                    || value.eq("true")
+                    || value.eq("false")
+                    || value.eq("not");
 
                 if is_keyword {
                     last_type = TokenType::Keyword;
@@ -96,7 +121,8 @@ pub fn tokenize(source: &str) -> TokenVec {
                 tokens.push(Token::new(TextType::Paren, String::from(ch)));
             }
             // Computing symbols
-            '+' | '-' | '*' | '/' | '^' | '!' | '<' | '>' | '=' | '.' => {
+            '+' | '-' | '*' | '/' | '%' | '^' | '!' | '<' | '>' | '=' | '.' | '&' | '|' | '~'
+            | '?' | ':' => {
                 last_type = TokenType::Symbol;
                 tokens.push(Token::new(TextType::Symbol, String::from(ch)));
             }